@@ -16,7 +16,7 @@ impl CalibrationValue {
         (first * 10) + last
     }
 
-    pub fn replace_words(&mut self) -> () {
+    pub fn replace_words(&self) -> CalibrationValue {
         let str: String = self.raw.iter().collect();
         // words in the input may share contiguous characters with other words (e.g. "oneight")
         let res = str
@@ -29,7 +29,9 @@ impl CalibrationValue {
             .replace("seven", "s7n")
             .replace("eight", "e8t")
             .replace("nine", "n9e");
-        self.raw = res.chars().collect();
+        CalibrationValue {
+            raw: res.chars().collect(),
+        }
     }
 }
 
@@ -74,8 +76,7 @@ mod tests {
 
     #[test]
     fn replace_words() {
-        let mut cv = CalibrationValue::from("onetwothreefourfivesixseveneightnine");
-        cv.replace_words();
+        let cv = CalibrationValue::from("onetwothreefourfivesixseveneightnine").replace_words();
         assert_eq!(cv.to_u32(), 19);
     }
 }
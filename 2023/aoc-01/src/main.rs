@@ -0,0 +1,45 @@
+mod calibration_value;
+
+use crate::calibration_value::CalibrationValue;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<CalibrationValue>;
+    const PART_ONE: &'static str = "Sum of all calibration values using only digits";
+    const PART_TWO: &'static str = "Sum of all calibration values using digits and words";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        Ok(input.to_lines().map(CalibrationValue::from).collect())
+    }
+
+    fn part_one(values: &Self::Input) -> impl Display {
+        values.iter().map(|cv| cv.to_u32()).sum::<u32>()
+    }
+
+    fn part_two(values: &Self::Input) -> impl Display {
+        values
+            .iter()
+            .map(|cv| cv.replace_words().to_u32())
+            .sum::<u32>()
+    }
+}
+
+fn main() {
+    run::<Day01>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day01>();
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_core::includes::Includes;
+use aoc_core::scanner::{ParseError, Scanner};
+use std::cmp::max;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct CubeBag {
+    pub id: u32,
+    green: u8,
+    red: u8,
+    blue: u8,
+}
+
+impl CubeBag {
+    pub fn to_power(&self) -> u32 {
+        (self.green as u32) * (self.red as u32) * (self.blue as u32)
+    }
+}
+
+impl Includes<CubeBag> for CubeBag {
+    fn includes(&self, other: &CubeBag) -> bool {
+        self.green >= other.green && self.blue >= other.blue && self.red >= other.red
+    }
+}
+
+impl CubeBag {
+    /// Reads a line like `Game 1: 3 blue, 4 red; 1 red, 2 green`, keeping the most cubes of each
+    /// colour seen in any one grab.
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        s.literal("Game")?;
+        let id = s.unsigned()?;
+        s.literal(":")?;
+
+        let mut red = 0u8;
+        let mut green = 0u8;
+        let mut blue = 0u8;
+        s.separated(";", |s| {
+            s.separated(",", |s| {
+                let count: u8 = s.unsigned()?;
+                let at = *s;
+                match s.word()? {
+                    "green" => green = max(green, count),
+                    "red" => red = max(red, count),
+                    "blue" => blue = max(blue, count),
+                    _ => return Err(at.error("red, green or blue")),
+                }
+                Ok(())
+            })
+        })?;
+
+        Ok(CubeBag {
+            id,
+            red,
+            green,
+            blue,
+        })
+    }
+}
+
+impl FromStr for CubeBag {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, CubeBag::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cube_bag::CubeBag;
+    use aoc_core::scanner::Scanner;
+
+    #[test]
+    fn from_str() {
+        let cv: CubeBag = "Game 84: 16 red, 2 green, 6 blue; 6 red, 3 green, 8 blue; 3 green, 10 red, 5 blue; 4 blue, 3 green; 15 red".parse().unwrap();
+        assert_eq!(cv.id, 84);
+        assert_eq!(cv.red, 16);
+        assert_eq!(cv.green, 3);
+        assert_eq!(cv.blue, 8);
+    }
+
+    #[test]
+    fn reports_bad_colour() {
+        let error = Scanner::parse("Game 1: 3 blue, 4 purple", CubeBag::parse).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 19));
+        assert_eq!(error.found, "\"purple\"");
+    }
+}
@@ -0,0 +1,48 @@
+mod cube_bag;
+
+use crate::cube_bag::CubeBag;
+use aoc_core::includes::Includes;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<CubeBag>;
+    const PART_ONE: &'static str = "Sum of ids of possible games";
+    const PART_TWO: &'static str = "Sum of powers of all games";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        input.parse_lines()
+    }
+
+    fn part_one(games: &Self::Input) -> impl Display {
+        let test: CubeBag = "Game 0: 12 red, 13 green, 14 blue".parse().unwrap();
+        games
+            .iter()
+            .filter(|c| test.includes(c))
+            .map(|c| c.id)
+            .sum::<u32>()
+    }
+
+    fn part_two(games: &Self::Input) -> impl Display {
+        games.iter().map(CubeBag::to_power).sum::<u32>()
+    }
+}
+
+fn main() {
+    run::<Day02>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day02>();
+    }
+}
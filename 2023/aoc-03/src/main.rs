@@ -0,0 +1,42 @@
+mod schematic;
+
+use crate::schematic::Schematic;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    const PART_ONE: &'static str = "Sum of all part numbers";
+    const PART_TWO: &'static str = "Sum of all gear ratios";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        input.parse()
+    }
+
+    fn part_one(schematic: &Self::Input) -> impl Display {
+        schematic.to_part_numbers().iter().sum::<u32>()
+    }
+
+    fn part_two(schematic: &Self::Input) -> impl Display {
+        schematic.to_gear_ratios().iter().sum::<u32>()
+    }
+}
+
+fn main() {
+    run::<Day03>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day03>();
+    }
+}
@@ -1,8 +1,11 @@
+use aoc_core::grid_view::{Colour, GridView, Style};
+use aoc_core::point::Point;
+use aoc_core::scanner::{InputError, ParseError, Scanner};
 use std::cmp::max;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter, Write};
-use std::str::Lines;
+use std::str::FromStr;
 
 trait SchematicPartLocation {
     fn to_position(&self) -> (u8, u8);
@@ -214,77 +217,112 @@ impl Schematic {
     }
 }
 
-impl From<Lines<'_>> for Schematic {
-    fn from(value: Lines) -> Self {
-        let mut parts: Vec<SchematicPart> = vec![];
-        let mut number_buffer = String::from("");
-        let mut width = 0u8;
-        for (y, line) in value.enumerate() {
-            width = max(width, line.len() as u8);
-            for (x, c) in line.chars().enumerate() {
-                if c.is_numeric() {
-                    number_buffer.push(c);
-                    continue;
-                }
-                if !&number_buffer.is_empty() {
-                    let size = number_buffer.len() as u8;
-                    parts.push(SchematicPart::Number(SchematicPartNumber {
-                        x: (x as u8) - size,
-                        y: y as u8,
-                        size,
-                        value: number_buffer.parse().unwrap(),
-                    }));
-                    number_buffer.clear();
-                }
-                if c == '.' {
-                    continue;
-                }
-                parts.push(SchematicPart::Symbol(SchematicPartSymbol {
-                    x: x as u8,
-                    y: y as u8,
-                    value: c,
-                }));
-            }
-            if !&number_buffer.is_empty() {
-                let size = number_buffer.len() as u8;
+impl Schematic {
+    /// Reads one row of the schematic, pushing any numbers and symbols found on it into `parts`.
+    fn parse_row(s: &mut Scanner, parts: &mut Vec<SchematicPart>) -> Result<(), ParseError> {
+        while let Some(c) = s.peek().filter(|_| !s.is_at_line_end()) {
+            let (line, column) = s.position();
+            let (x, y) = ((column - 1) as u8, (line - 1) as u8);
+            if c.is_ascii_digit() {
+                let value = s.unsigned()?;
+                let size = (s.position().1 - column) as u8;
                 parts.push(SchematicPart::Number(SchematicPartNumber {
-                    x: (line.len() as u8) - size,
-                    y: y as u8,
+                    x,
+                    y,
                     size,
-                    value: number_buffer.parse().unwrap(),
+                    value,
+                }));
+            } else if s.char()? != '.' {
+                parts.push(SchematicPart::Symbol(SchematicPartSymbol {
+                    x,
+                    y,
+                    value: c,
                 }));
-                number_buffer.clear();
             }
         }
-        Schematic::new(parts, width + 1)
+        Ok(())
     }
 }
 
-/// Just seeing if I can reproduce the input.
+impl FromStr for Schematic {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<SchematicPart> = vec![];
+        Scanner::new(s).lines(|s| Schematic::parse_row(s, &mut parts))?;
+        let width = s.lines().map(|line| line.len() as u8).fold(0, max);
+        Ok(Schematic::new(parts, width + 1))
+    }
+}
+
+/// Draws the schematic as it was read. With `{:#}`, part numbers are coloured green, numbers that
+/// aren't parts red, and symbols yellow.
 impl Display for Schematic {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut parts = self.parts.iter();
-        let mut x_pos = 0u8;
-        let mut y_pos = 0u8;
-        while let Some(n) = parts.next() {
-            let (x, y) = n.to_position();
-            // write dots up until the next part
-            while y_pos < y {
-                while x_pos < self.width {
-                    f.write_char('.')?;
-                    x_pos += 1;
-                }
-                f.write_char('\n')?;
-                x_pos = 0;
-                y_pos += 1;
+        let height = self
+            .parts
+            .iter()
+            .map(|p| p.to_position().1 as usize + 1)
+            .max()
+            .unwrap_or(0);
+        let mut grid = vec![vec!['.'; self.width as usize - 1]; height];
+        let (mut parts, mut others, mut symbols) = (vec![], vec![], vec![]);
+        let is_part_number = |n: &SchematicPartNumber| {
+            n.get_surrounding_positions()
+                .iter()
+                .any(|(x, y)| self.get_symbol_at(x, y).is_some())
+        };
+        for part in self.parts.iter() {
+            let (x, y) = part.to_position();
+            let points = (0..part.to_size()).map(|i| Point::new((x + i) as usize, y as usize));
+            let glyphs = part.to_string();
+            for (point, glyph) in points.clone().zip(glyphs.chars()) {
+                grid[point.y][point.x] = glyph;
             }
-            while x_pos < x {
-                f.write_char('.')?;
-                x_pos += 1;
+            match part {
+                SchematicPart::Number(n) if is_part_number(n) => parts.extend(points),
+                SchematicPart::Number(_) => others.extend(points),
+                SchematicPart::Symbol(_) => symbols.extend(points),
             }
-            n.fmt(f)?;
-            x_pos += n.to_size();
         }
-        Ok(())
+
+        GridView::new(&grid)
+            .colour(f.alternate())
+            .highlight(parts, Style::fg(Colour::Green))
+            .highlight(others, Style::fg(Colour::Red).dim())
+            .highlight(symbols, Style::fg(Colour::Yellow).bold())
+            .fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schematic::Schematic;
+
+    const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn sums_example() {
+        let schematic: Schematic = INPUT.parse().unwrap();
+        assert_eq!(schematic.to_part_numbers().iter().sum::<u32>(), 4361);
+        assert_eq!(schematic.to_gear_ratios().iter().sum::<u32>(), 467835);
+    }
+
+    #[test]
+    fn draws_the_input() {
+        let schematic: Schematic = INPUT.parse().unwrap();
+        assert_eq!(schematic.to_string(), INPUT);
+        let coloured = format!("{:#}", schematic);
+        assert!(coloured.starts_with("\x1b[32m467\x1b[0m..\x1b[2;31m114\x1b[0m..\n"));
     }
 }
@@ -0,0 +1,42 @@
+mod scratchcard;
+
+use crate::scratchcard::{Scratchcard, ScratchcardStack};
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day04;
+
+impl Solution for Day04 {
+    type Input = ScratchcardStack;
+    const PART_ONE: &'static str = "Sum of scratchcard point values";
+    const PART_TWO: &'static str = "Sum of scratchcard copies";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        Ok(input.parse_lines::<Scratchcard>()?.into())
+    }
+
+    fn part_one(scratchcards: &Self::Input) -> impl Display {
+        scratchcards.to_point_sum()
+    }
+
+    fn part_two(scratchcards: &Self::Input) -> impl Display {
+        scratchcards.to_copies_sum()
+    }
+}
+
+fn main() {
+    run::<Day04>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day04;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day04>();
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_core::scanner::{ParseError, Scanner};
+use aoc_core::set::bit_set::BitSet128;
+use aoc_core::set::SetIntersection;
+use std::str::FromStr;
+
+pub struct Scratchcard {
+    id: u8,
+    winners: BitSet128,
+    numbers: BitSet128,
+    copies: u32,
+}
+
+impl Scratchcard {
+    fn to_points(&self) -> u32 {
+        let winner_count = self.to_winner_count();
+        if winner_count == 0 {
+            0
+        } else {
+            2u32.pow(winner_count - 1)
+        }
+    }
+
+    fn to_winner_count(&self) -> u32 {
+        self.winners.intersection(&self.numbers).len() as u32
+    }
+}
+
+impl Scratchcard {
+    /// Reads a line like `Card 1: 41 48 83 | 83 86 6 31`.
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        s.literal("Card")?;
+        let id = s.unsigned()?;
+        s.literal(":")?;
+        let winners = Scratchcard::parse_numbers(s)?;
+        s.literal("|")?;
+        let numbers = Scratchcard::parse_numbers(s)?;
+
+        Ok(Scratchcard {
+            id,
+            winners,
+            numbers,
+            copies: 1,
+        })
+    }
+
+    fn parse_numbers(s: &mut Scanner) -> Result<BitSet128, ParseError> {
+        let mut numbers = BitSet128::new();
+        s.skip_whitespace();
+        while !s.is_at_line_end() && s.peek() != Some('|') {
+            let at = *s;
+            let number: usize = s.unsigned()?;
+            if number >= BitSet128::CAPACITY {
+                return Err(at.error("a number below 128"));
+            }
+            numbers.insert(number);
+            s.skip_whitespace();
+        }
+        Ok(numbers)
+    }
+}
+
+pub struct ScratchcardStack {
+    scratchcards: Vec<Scratchcard>,
+}
+
+impl ScratchcardStack {
+    pub fn to_point_sum(&self) -> u32 {
+        self.scratchcards.iter().map(Scratchcard::to_points).sum()
+    }
+
+    pub fn to_copies_sum(&self) -> u32 {
+        self.scratchcards.iter().map(|i| i.copies).sum()
+    }
+
+    fn count_copies(&mut self) {
+        for i in 0..self.scratchcards.len() {
+            let item = self.scratchcards.get(i).unwrap();
+            let copies = item.copies;
+            for k in i + 1..=i + (item.to_winner_count() as usize) {
+                if let Some(c) = self.scratchcards.get_mut(k) {
+                    c.copies += 1 * copies;
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Scratchcard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, Scratchcard::parse)
+    }
+}
+
+impl From<Vec<Scratchcard>> for ScratchcardStack {
+    fn from(value: Vec<Scratchcard>) -> Self {
+        let mut stack = ScratchcardStack {
+            scratchcards: value,
+        };
+        stack.count_copies();
+
+        stack
+    }
+}
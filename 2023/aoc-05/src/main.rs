@@ -0,0 +1,42 @@
+mod seed_map;
+
+use crate::seed_map::SeedAlmanac;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day05;
+
+impl Solution for Day05 {
+    type Input = SeedAlmanac;
+    const PART_ONE: &'static str = "Min location number as individual seeds";
+    const PART_TWO: &'static str = "Min location number as ranges of seeds";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        SeedAlmanac::try_from(input.as_str())
+    }
+
+    fn part_one(almanac: &Self::Input) -> impl Display {
+        *almanac.to_location_individual().iter().min().unwrap()
+    }
+
+    fn part_two(almanac: &Self::Input) -> impl Display {
+        almanac.to_location_range_min()
+    }
+}
+
+fn main() {
+    run::<Day05>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day05>();
+    }
+}
@@ -0,0 +1,478 @@
+use aoc_core::map::range_map::RangeMap;
+use aoc_core::map::Shift;
+use aoc_core::scanner::{InputError, ParseError, Scanner};
+use aoc_core::set::range_set::RangeSet;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+pub struct SeedAlmanac {
+    seeds: Vec<u64>,
+    /// By source category name.
+    maps: HashMap<String, SeedMap>,
+    /// The maps from "seed" to "location", composed into a single map.
+    composed: SeedMap,
+}
+
+impl SeedAlmanac {
+    pub fn to_location_individual(&self) -> Vec<u64> {
+        self.seeds.iter().map(|s| self.to_location(s)).collect()
+    }
+
+    pub fn to_location_range_min(&self) -> u64 {
+        let seed_ranges = self
+            .seeds
+            .chunks(2)
+            .map(|v| {
+                let start = v.get(0).unwrap();
+                let size = v.get(1).unwrap();
+                *start..start + size
+            })
+            .collect();
+        let range_set = RangeSet::new(seed_ranges);
+        self.composed.map_range_set(&range_set).min().unwrap()
+    }
+
+    pub fn to_location(&self, seed: &u64) -> u64 {
+        self.composed.map(seed)
+    }
+
+    /// Returns every seed that ends up at `location`, in ascending order.
+    #[allow(dead_code)] // neither part needs it, but it helps when exploring the input
+    pub fn to_seeds(&self, location: &u64) -> Vec<u64> {
+        self.composed.invert(location)
+    }
+
+    /// Converts `value` from the `from` category to the `to` category, following the chain of
+    /// maps between them.
+    #[allow(dead_code)] // kept for ad-hoc queries between categories
+    pub fn convert(&self, from: &str, to: &str, value: &u64) -> Result<u64, SeedAlmanacError> {
+        Ok(self.chain(from, to)?.map(value))
+    }
+
+    /// Composes the maps leading from the `from` category to the `to` category. Each category
+    /// has at most one map out of it, so the chain is found by following those maps in turn.
+    fn chain(&self, from: &str, to: &str) -> Result<SeedMap, SeedAlmanacError> {
+        for category in [from, to] {
+            if !self.is_category(category) {
+                return Err(SeedAlmanacError::UnknownCategory(category.to_owned()));
+            }
+        }
+        let mut visited = vec![from.to_owned()];
+        let mut result = SeedMap::identity(from);
+        while result.dest_name != to {
+            let next =
+                self.maps
+                    .get(&result.dest_name)
+                    .ok_or_else(|| SeedAlmanacError::MissingMap {
+                        from: from.to_owned(),
+                        to: to.to_owned(),
+                        dead_end: result.dest_name.clone(),
+                    })?;
+            if visited.contains(&next.dest_name) {
+                visited.push(next.dest_name.clone());
+                return Err(SeedAlmanacError::Cycle(visited));
+            }
+            visited.push(next.dest_name.clone());
+            result = result.then(next);
+        }
+        Ok(result)
+    }
+
+    fn is_category(&self, name: &str) -> bool {
+        self.maps.contains_key(name) || self.maps.values().any(|m| m.dest_name == name)
+    }
+}
+
+impl TryFrom<&str> for SeedAlmanac {
+    type Error = InputError;
+
+    /// Maps that don't link up from "seed" to "location" are reported as a [ParseError] too, at
+    /// the map out of place or at the end of the input for a map that's missing.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // Bad mapping lines are collected so they can all be reported at once
+        let mut errors = vec![];
+        let (seeds, parsed_maps, end) = Scanner::parse(value, |s| {
+            s.literal("seeds:")?;
+            let seeds = s.many(|s| s.unsigned());
+            s.line_end()?;
+            let mut maps = vec![];
+            s.skip_newlines();
+            while !s.is_at_end() {
+                let header = *s;
+                maps.push((header, SeedMap::parse(s, &mut errors)?));
+                s.skip_newlines();
+            }
+            Ok((seeds, maps, *s))
+        })?;
+        if !errors.is_empty() {
+            return Err(InputError { day: None, errors });
+        }
+        let mut maps = HashMap::new();
+        for (header, map) in parsed_maps {
+            if maps.contains_key(&map.source_name) {
+                let expected = format!("only one map from \"{}\"", map.source_name);
+                return Err(header.error(&expected).into());
+            }
+            maps.insert(map.source_name.clone(), map);
+        }
+        let mut almanac = SeedAlmanac {
+            seeds,
+            maps,
+            composed: SeedMap::identity("seed"),
+        };
+        almanac.composed = match almanac.chain("seed", "location") {
+            Ok(composed) => composed,
+            Err(SeedAlmanacError::UnknownCategory(name)) if name == "location" => {
+                return Err(end.error("a map to \"location\"").into());
+            }
+            Err(SeedAlmanacError::UnknownCategory(name))
+            | Err(SeedAlmanacError::MissingMap { dead_end: name, .. }) => {
+                let expected = format!("a map from \"{}\"", name);
+                return Err(end.error(&expected).into());
+            }
+            Err(SeedAlmanacError::Cycle(visited)) => {
+                let mut error = end.error("a map to \"location\"");
+                error.found = format!("a cycle through {}", visited.join(" -> "));
+                return Err(error.into());
+            }
+        };
+
+        Ok(almanac)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum SeedAlmanacError {
+    /// No map leads to or from the category.
+    UnknownCategory(String),
+    /// Following maps from `from` reached `dead_end`, which has no map out of it, before `to`.
+    MissingMap {
+        from: String,
+        to: String,
+        dead_end: String,
+    },
+    /// Following maps led back to a category already visited. Holds the categories in the order
+    /// they were visited.
+    Cycle(Vec<String>),
+}
+
+impl Display for SeedAlmanacError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeedAlmanacError::UnknownCategory(name) => {
+                f.write_fmt(format_args!("No map leads to or from \"{}\"", name))
+            }
+            SeedAlmanacError::MissingMap { from, to, dead_end } => f.write_fmt(format_args!(
+                "No chain of maps from \"{}\" to \"{}\": nothing maps from \"{}\"",
+                from, to, dead_end
+            )),
+            SeedAlmanacError::Cycle(visited) => {
+                f.write_fmt(format_args!("Maps form a cycle: {}", visited.join(" -> ")))
+            }
+        }
+    }
+}
+
+impl Error for SeedAlmanacError {}
+
+/// Dumps the composed seed-to-location table.
+impl Display for SeedAlmanac {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.composed.fmt(f)
+    }
+}
+
+#[derive(Clone)]
+struct SeedMap {
+    source_name: String,
+    dest_name: String,
+    /// The offset from source to destination for each source range.
+    mappings: RangeMap<u64, i64>,
+}
+
+impl SeedMap {
+    fn map(&self, input: &u64) -> u64 {
+        self.mappings.shift(*input)
+    }
+
+    fn map_range_set(&self, lhs: &RangeSet<u64>) -> RangeSet<u64> {
+        self.mappings.shift_set(lhs)
+    }
+
+    fn invert(&self, output: &u64) -> Vec<u64> {
+        self.mappings
+            .pieces(0..=u64::MAX)
+            .into_iter()
+            .filter_map(|(source, offset)| {
+                let input = *output as i128 - offset.copied().unwrap_or(0) as i128;
+                let input = u64::try_from(input).ok()?;
+                source.contains(&input).then_some(input)
+            })
+            .collect()
+    }
+
+    /// Returns the map that leaves every value of `category` as it is.
+    fn identity(category: &str) -> SeedMap {
+        SeedMap {
+            source_name: category.to_owned(),
+            dest_name: category.to_owned(),
+            mappings: RangeMap::new(),
+        }
+    }
+
+    /// Returns the map equivalent to applying `self`, then `next`.
+    fn then(&self, next: &SeedMap) -> SeedMap {
+        // maps are only ever chained by category name, so they always line up
+        debug_assert_eq!(self.dest_name, next.source_name);
+        SeedMap {
+            source_name: self.source_name.clone(),
+            dest_name: next.dest_name.clone(),
+            mappings: self.mappings.then(&next.mappings),
+        }
+    }
+}
+
+impl Display for SeedMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{}-to-{} map:\n",
+            self.source_name, self.dest_name
+        ))?;
+        for (source, offset) in self.mappings.iter() {
+            f.write_fmt(format_args!(
+                "{}..={} -> {}..={} ({:+})\n",
+                source.start(),
+                source.end(),
+                offset.shift(*source.start()),
+                offset.shift(*source.end()),
+                offset
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+impl SeedMap {
+    /// Reads a block like `seed-to-soil map:` followed by one mapping per line. Bad mapping lines
+    /// are skipped and added to `errors`.
+    fn parse(s: &mut Scanner, errors: &mut Vec<ParseError>) -> Result<Self, ParseError> {
+        let source_name = s.word()?.to_owned();
+        s.literal("-to-")?;
+        let dest_name = s.word()?.to_owned();
+        s.literal("map:")?;
+        s.line_end()?;
+        let mut mappings = RangeMap::new();
+        while s.peek().is_some_and(|c| c.is_ascii_digit()) {
+            match SeedMapping::parse(s) {
+                Ok(mapping) => mappings.insert_range(mapping.source, mapping.offset),
+                Err(error) => {
+                    errors.push(error);
+                    // skip what's left of the bad line so the next one can still be read
+                    s.rest_of_line();
+                    s.line_end()?;
+                }
+            }
+        }
+
+        Ok(SeedMap {
+            source_name,
+            dest_name,
+            mappings,
+        })
+    }
+}
+
+struct SeedMapping {
+    source: Range<u64>,
+    offset: i64,
+}
+
+impl SeedMapping {
+    /// Reads a line holding the destination start, source start and length of a range.
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        let dest: u64 = s.unsigned()?;
+        let source: u64 = s.unsigned()?;
+        let range: u64 = s.unsigned()?;
+        s.line_end()?;
+
+        Ok(SeedMapping {
+            source: source..source + range,
+            offset: dest as i64 - source as i64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::seed_map::{SeedAlmanac, SeedAlmanacError};
+
+    const EXAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+
+    #[test]
+    fn to_location() {
+        let almanac = SeedAlmanac::try_from(EXAMPLE).unwrap();
+        assert_eq!(almanac.to_location_individual(), vec![82, 43, 86, 35]);
+        assert_eq!(almanac.to_location_range_min(), 46);
+    }
+
+    #[test]
+    fn to_seeds() {
+        let almanac = SeedAlmanac::try_from(EXAMPLE).unwrap();
+        assert_eq!(almanac.to_seeds(&82), vec![79]);
+        for seed in 0..120 {
+            let location = almanac.to_location(&seed);
+            assert!(almanac.to_seeds(&location).contains(&seed));
+        }
+    }
+
+    #[test]
+    fn dump() {
+        let almanac = SeedAlmanac::try_from(EXAMPLE).unwrap();
+        let table = almanac.to_string();
+        assert!(table.starts_with("seed-to-location map:\n"));
+        assert!(table.contains("\n71..=81 -> 74..=84 (+3)\n"));
+        assert_eq!(table.lines().count(), 21);
+    }
+
+    #[test]
+    fn traces_lowest_location_back_to_soil() {
+        let almanac = SeedAlmanac::try_from(EXAMPLE).unwrap();
+        let location = almanac.to_location_range_min();
+        let seeds = almanac.to_seeds(&location);
+        assert_eq!(seeds, vec![82]);
+        assert_eq!(almanac.convert("seed", "soil", &seeds[0]), Ok(84));
+        assert_eq!(almanac.convert("seed", "location", &seeds[0]), Ok(location));
+    }
+
+    #[test]
+    fn convert_between_categories() {
+        let almanac = SeedAlmanac::try_from(EXAMPLE).unwrap();
+        assert_eq!(almanac.convert("seed", "soil", &79), Ok(81));
+        assert_eq!(almanac.convert("seed", "humidity", &79), Ok(78));
+        assert_eq!(almanac.convert("water", "temperature", &81), Ok(78));
+        assert_eq!(almanac.convert("light", "light", &5), Ok(5));
+    }
+
+    #[test]
+    fn ignores_file_order() {
+        let mut sections: Vec<&str> = EXAMPLE.split("\n\n").collect();
+        sections[1..].reverse();
+        let almanac = SeedAlmanac::try_from(sections.join("\n\n").as_str()).unwrap();
+        assert_eq!(almanac.to_location_individual(), vec![82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn missing_chain() {
+        let sections: Vec<&str> = EXAMPLE
+            .split("\n\n")
+            .filter(|s| !s.starts_with("water"))
+            .collect();
+        let err = SeedAlmanac::try_from(sections.join("\n\n").as_str())
+            .err()
+            .unwrap();
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].expected, "a map from \"water\"");
+        assert_eq!(err.errors[0].found, "end of input");
+
+        let almanac = SeedAlmanac::try_from(EXAMPLE).unwrap();
+        assert_eq!(
+            almanac.convert("seed", "bogus", &1),
+            Err(SeedAlmanacError::UnknownCategory("bogus".to_owned()))
+        );
+        assert!(almanac.convert("location", "seed", &1).is_err());
+    }
+
+    #[test]
+    fn cyclic_chain() {
+        let input = "seeds: 1\n\na-to-b map:\n0 0 1\n\nb-to-a map:\n0 0 1\n\nseed-to-c map:\n0 0 1\n\nc-to-location map:\n0 0 1";
+        let almanac = SeedAlmanac::try_from(input).unwrap();
+        let err = almanac.convert("a", "location", &0).unwrap_err();
+        assert_eq!(
+            err,
+            SeedAlmanacError::Cycle(vec!["a".to_owned(), "b".to_owned(), "a".to_owned()])
+        );
+        assert_eq!(err.to_string(), "Maps form a cycle: a -> b -> a");
+
+        let input = "seeds: 1\n\nseed-to-a map:\n0 0 1\n\na-to-seed map:\n0 0 1\n\nb-to-location map:\n0 0 1\n";
+        let err = SeedAlmanac::try_from(input).err().unwrap();
+        assert_eq!(
+            err.errors[0].to_string(),
+            "line 11, column 1: expected a map to \"location\", found a cycle through seed -> a -> seed"
+        );
+    }
+
+    #[test]
+    fn reports_misplaced_maps() {
+        let input = "seeds: 1\n\nseed-to-location map:\n0 0 1\n\nseed-to-soil map:\n0 0 1\n";
+        let err = SeedAlmanac::try_from(input).err().unwrap();
+        assert_eq!(
+            err.errors[0].to_string(),
+            "line 6, column 1: expected only one map from \"seed\", found \"seed-to-soil\""
+        );
+
+        let err = SeedAlmanac::try_from("seeds: 1\n\nseed-to-soil map:\n0 0 1")
+            .err()
+            .unwrap();
+        assert_eq!(err.errors[0].expected, "a map to \"location\"");
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        let input = "seeds: 1 2\n\nseed-to-location map:\n0 0 1\n0 x 1";
+        let err = SeedAlmanac::try_from(input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1 bad line in puzzle input\n  line 5, column 3: expected u64, found \"x\""
+        );
+
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n0 0 1\n0 1\n\nsoil-to-location map:\n7\n";
+        let err = SeedAlmanac::try_from(input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "2 bad lines in puzzle input\n  \
+             line 5, column 4: expected u64, found end of line\n  \
+             line 8, column 2: expected u64, found end of line"
+        );
+
+        let input = "seeds: 1 99999999999999999999\n\nseed-to-location map:\n0 0 1";
+        let err = SeedAlmanac::try_from(input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1 bad line in puzzle input\n  line 1, column 10: expected end of line, found \"99999999999999999999\""
+        );
+    }
+}
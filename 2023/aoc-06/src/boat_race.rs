@@ -0,0 +1,123 @@
+use aoc_core::scanner::{ParseError, Scanner};
+use std::str::FromStr;
+
+pub struct BoatRace {
+    time: u64,
+    distance: u64,
+}
+
+impl BoatRace {
+    pub fn count_winning_holds(&self) -> u64 {
+        (1..self.time)
+            .into_iter()
+            .map(|x| self.get_distance_for_hold(&x))
+            .filter(|x| x > &self.distance)
+            .count() as u64
+    }
+
+    fn get_distance_for_hold(&self, hold_for: &u64) -> u64 {
+        if hold_for >= &self.time {
+            return 0;
+        }
+        let runtime = self.time - hold_for;
+        runtime * hold_for
+    }
+}
+
+impl BoatRace {
+    /// Reads both lines of the sheet as a single race, ignoring the spaces between digits.
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        let time = BoatRace::parse_kerned(s, "Time:")?;
+        s.line_end()?;
+        let distance = BoatRace::parse_kerned(s, "Distance:")?;
+        Ok(BoatRace { time, distance })
+    }
+
+    fn parse_kerned(s: &mut Scanner, label: &str) -> Result<u64, ParseError> {
+        s.literal(label)?;
+        s.skip_whitespace();
+        let start = *s;
+        let digits: String = s.many(|s| s.digits()).concat();
+        digits.parse().map_err(|_| start.error("u64"))
+    }
+}
+
+impl FromStr for BoatRace {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, BoatRace::parse)
+    }
+}
+
+pub struct BoatRaceSeries {
+    races: Vec<BoatRace>,
+}
+
+impl BoatRaceSeries {
+    pub fn get_winning_race_product(&self) -> u64 {
+        self.races
+            .iter()
+            .map(BoatRace::count_winning_holds)
+            .fold(1, |acc, val| acc * val)
+    }
+}
+
+impl BoatRaceSeries {
+    /// Reads a `Time:` line and a `Distance:` line holding one column per race.
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        s.literal("Time:")?;
+        let times: Vec<u64> = s.many(|s| s.unsigned());
+        s.line_end()?;
+        s.literal("Distance:")?;
+        let distances: Vec<u64> = s.many(|s| s.unsigned());
+        if distances.len() != times.len() {
+            return Err(s.error(&format!("{} distances", times.len())));
+        }
+        let races = times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| BoatRace { time, distance })
+            .collect();
+
+        Ok(BoatRaceSeries { races })
+    }
+}
+
+impl FromStr for BoatRaceSeries {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, BoatRaceSeries::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::boat_race::{BoatRace, BoatRaceSeries};
+
+    const EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+    #[test]
+    fn from_str() {
+        let series: BoatRaceSeries = EXAMPLE.parse().ok().unwrap();
+        assert_eq!(series.get_winning_race_product(), 288);
+        let race: BoatRace = EXAMPLE.parse().ok().unwrap();
+        assert_eq!(race.count_winning_holds(), 71503);
+    }
+
+    #[test]
+    fn reports_bad_sheet() {
+        let error = "Time: 7 15\nDistance: 9 x"
+            .parse::<BoatRaceSeries>()
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 13));
+        assert_eq!(error.expected, "2 distances");
+        let error = "Time: 7 15 x\nDistance: 9 40"
+            .parse::<BoatRaceSeries>()
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 12));
+    }
+}
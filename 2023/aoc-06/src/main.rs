@@ -0,0 +1,45 @@
+mod boat_race;
+
+use crate::boat_race::{BoatRace, BoatRaceSeries};
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day06;
+
+impl Solution for Day06 {
+    /// The sheet read as separate races, then as a single race.
+    type Input = (BoatRaceSeries, BoatRace);
+    const PART_ONE: &'static str = "Product of winning races";
+    const PART_TWO: &'static str = "Count of single race wins";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        let series: BoatRaceSeries = input.parse()?;
+        let race: BoatRace = input.parse()?;
+        Ok((series, race))
+    }
+
+    fn part_one((series, _): &Self::Input) -> impl Display {
+        series.get_winning_race_product()
+    }
+
+    fn part_two((_, race): &Self::Input) -> impl Display {
+        race.count_winning_holds()
+    }
+}
+
+fn main() {
+    run::<Day06>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day06>();
+    }
+}
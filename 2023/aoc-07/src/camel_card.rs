@@ -1,7 +1,8 @@
+use aoc_core::scanner::{ParseError, Scanner};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Write};
-use std::str::Lines;
+use std::str::FromStr;
 
 #[derive(Eq, PartialEq)]
 enum CamelCardHandType {
@@ -40,8 +41,9 @@ impl Ord for CamelCardHandType {
     }
 }
 
-impl From<&CamelCardHand> for CamelCardHandType {
-    fn from(value: &CamelCardHand) -> Self {
+impl CamelCardHandType {
+    /// Classifies a hand, counting each `J` towards the best group when `wildcards` is set.
+    fn new(value: &CamelCardHand, wildcards: bool) -> Self {
         let mut counts: HashMap<&char, u8> = HashMap::new();
         let mut jokers = 0u8;
         for c in value.hand.iter() {
@@ -55,13 +57,13 @@ impl From<&CamelCardHand> for CamelCardHandType {
         pairs.sort_by(|(_, a), (_, b)| b.cmp(a));
         let mut iter = pairs.iter();
         while let Some((c, raw)) = iter.next() {
-            if value.wildcards && **c == 'J' {
+            if wildcards && **c == 'J' {
                 if *raw == 5 {
                     return CamelCardHandType::FiveKind;
                 }
                 continue;
             }
-            let count = if value.wildcards { raw + jokers } else { *raw };
+            let count = if wildcards { raw + jokers } else { *raw };
             if count >= 5 {
                 return CamelCardHandType::FiveKind;
             }
@@ -92,14 +94,13 @@ impl From<&CamelCardHand> for CamelCardHandType {
     }
 }
 
-struct CamelCardHand {
+pub struct CamelCardHand {
     hand: [char; 5],
     bid: u64,
-    wildcards: bool,
 }
 
 impl CamelCardHand {
-    fn get_char_strength(&self, i: usize) -> u8 {
+    fn get_char_strength(&self, i: usize, wildcards: bool) -> u8 {
         match self.hand[i] {
             '2' => 2,
             '3' => 3,
@@ -110,7 +111,7 @@ impl CamelCardHand {
             '8' => 8,
             '9' => 9,
             'T' => 10,
-            'J' => match self.wildcards {
+            'J' => match wildcards {
                 true => 1,
                 false => 11,
             },
@@ -138,14 +139,22 @@ impl PartialOrd<Self> for CamelCardHand {
 
 impl Ord for CamelCardHand {
     fn cmp(&self, other: &Self) -> Ordering {
-        let self_type = CamelCardHandType::from(self);
-        let other_type = CamelCardHandType::from(other);
+        self.cmp_with(other, false)
+    }
+}
+
+impl CamelCardHand {
+    fn cmp_with(&self, other: &Self, wildcards: bool) -> Ordering {
+        let self_type = CamelCardHandType::new(self, wildcards);
+        let other_type = CamelCardHandType::new(other, wildcards);
         let type_cmp = self_type.cmp(&other_type);
         if type_cmp != Ordering::Equal {
             return type_cmp;
         }
         for i in 0usize..5 {
-            let c_cmp = self.get_char_strength(i).cmp(&other.get_char_strength(i));
+            let c_cmp = self
+                .get_char_strength(i, wildcards)
+                .cmp(&other.get_char_strength(i, wildcards));
             if c_cmp != Ordering::Equal {
                 return c_cmp;
             }
@@ -154,20 +163,16 @@ impl Ord for CamelCardHand {
     }
 }
 
-impl From<&str> for CamelCardHand {
-    fn from(value: &str) -> Self {
+impl CamelCardHand {
+    /// Reads a line holding five cards and a bid, like `32T3K 765`.
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
         let mut hand = [' '; 5];
-        let mut split = value.split(' ');
-        for (i, char) in split.next().unwrap().chars().enumerate() {
-            hand[i] = char;
+        for card in hand.iter_mut() {
+            *card = s.one_of("AKQJT98765432")?;
         }
-        let bid = split.next().unwrap().parse().unwrap();
+        let bid = s.unsigned()?;
 
-        CamelCardHand {
-            hand,
-            bid,
-            wildcards: false,
-        }
+        Ok(CamelCardHand { hand, bid })
     }
 }
 
@@ -187,8 +192,18 @@ pub struct CamelCardHandSet {
 
 impl CamelCardHandSet {
     pub fn to_score(&self) -> u64 {
-        self.hands
-            .iter()
+        Self::score(self.hands.iter())
+    }
+
+    /// Scores the hands ranked with `J` as a joker, leaving the set in its plain ranking.
+    pub fn to_wildcard_score(&self) -> u64 {
+        let mut hands: Vec<&CamelCardHand> = self.hands.iter().collect();
+        hands.sort_by(|a, b| a.cmp_with(b, true));
+        Self::score(hands.into_iter())
+    }
+
+    fn score<'a>(hands: impl Iterator<Item = &'a CamelCardHand>) -> u64 {
+        hands
             .enumerate()
             .map(|(i, hand)| {
                 let rank = (i as u64) + 1;
@@ -196,18 +211,18 @@ impl CamelCardHandSet {
             })
             .sum()
     }
+}
 
-    pub fn set_wildcards(&mut self, wildcards: bool) {
-        for hand in self.hands.iter_mut() {
-            hand.wildcards = wildcards;
-        }
-        self.hands.sort();
+impl FromStr for CamelCardHand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, CamelCardHand::parse)
     }
 }
 
-impl From<Lines<'_>> for CamelCardHandSet {
-    fn from(value: Lines<'_>) -> Self {
-        let mut hands: Vec<CamelCardHand> = value.into_iter().map(|x| x.into()).collect();
+impl From<Vec<CamelCardHand>> for CamelCardHandSet {
+    fn from(mut hands: Vec<CamelCardHand>) -> Self {
         hands.sort();
         CamelCardHandSet { hands }
     }
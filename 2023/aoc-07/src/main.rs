@@ -0,0 +1,42 @@
+mod camel_card;
+
+use crate::camel_card::{CamelCardHand, CamelCardHandSet};
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day07;
+
+impl Solution for Day07 {
+    type Input = CamelCardHandSet;
+    const PART_ONE: &'static str = "Sum of all ranked scores";
+    const PART_TWO: &'static str = "Sum of all ranked scores with wildcard rule";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        Ok(input.parse_lines::<CamelCardHand>()?.into())
+    }
+
+    fn part_one(hand_set: &Self::Input) -> impl Display {
+        hand_set.to_score()
+    }
+
+    fn part_two(hand_set: &Self::Input) -> impl Display {
+        hand_set.to_wildcard_score()
+    }
+}
+
+fn main() {
+    run::<Day07>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day07>();
+    }
+}
@@ -0,0 +1,318 @@
+use aoc_core::cardinal_direction::{CardinalDirection, DirectionAlphabet};
+use aoc_core::scanner::{InputError, ParseError, Scanner};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The full cycle structure of a single ghost's walk. A ghost's state is the pair of its current
+/// node and its position in the instruction list, so every walk eventually falls into a loop.
+struct DesertMapCycle {
+    /// The number of steps taken before the ghost enters its loop.
+    offset: u64,
+    /// The length of the loop.
+    period: u64,
+    /// Steps before `offset` at which the ghost stands on a node ending in 'Z'.
+    prefix_hits: Vec<u64>,
+    /// Steps in `offset..offset + period` at which the ghost stands on a node ending in 'Z'.
+    cycle_hits: Vec<u64>,
+}
+
+impl DesertMapCycle {
+    fn is_hit(&self, step: u64) -> bool {
+        if step < self.offset {
+            self.prefix_hits.contains(&step)
+        } else {
+            let residue = (step - self.offset) % self.period;
+            self.cycle_hits.contains(&(self.offset + residue))
+        }
+    }
+}
+
+pub struct DesertMap {
+    instructions: Vec<CardinalDirection>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl DesertMap {
+    pub fn navigate(&self) -> u64 {
+        let mut current: &str = "AAA";
+        let mut steps = 0u64;
+        let mut iter = self.instructions.iter();
+        while current != "ZZZ" {
+            let next_instruction = iter.next();
+            if let Some(dir) = next_instruction {
+                steps += 1;
+                current = self.get_next(current, dir);
+            } else {
+                iter = self.instructions.iter();
+            }
+        }
+
+        steps
+    }
+
+    /// Returns the first step at which every ghost stands on a node ending in 'Z', or `None` if
+    /// that never happens. Every 'Z' hit in each ghost's walk is considered, so this holds for
+    /// any map, not only ones shaped like the puzzle input.
+    pub fn navigate_ghost(&self) -> Option<u64> {
+        let cycles: Vec<DesertMapCycle> = self
+            .nodes
+            .keys()
+            .filter(|x| x.ends_with('A'))
+            .map(|x| self.find_cycle(x))
+            .collect();
+        if cycles.is_empty() {
+            return None;
+        }
+
+        // Any step before the latest loop entry must be a prefix hit of the ghost that enters last.
+        let latest = cycles.iter().max_by_key(|c| c.offset).unwrap();
+        let early = latest
+            .prefix_hits
+            .iter()
+            .find(|step| cycles.iter().all(|c| c.is_hit(**step)));
+        if let Some(step) = early {
+            return Some(*step);
+        }
+
+        // From then on each ghost is periodic, so combine every choice of residues.
+        let mut candidates: Vec<(i128, i128)> = vec![(0, 1)];
+        for cycle in cycles.iter() {
+            let period = cycle.period as i128;
+            let mut next = vec![];
+            for (residue, modulus) in candidates.iter() {
+                for hit in cycle.cycle_hits.iter() {
+                    let hit_residue = (*hit as i128) % period;
+                    if let Some(c) = crt((*residue, *modulus), (hit_residue, period)) {
+                        next.push(c);
+                    }
+                }
+            }
+            candidates = next;
+        }
+
+        let min_step = latest.offset as i128;
+        candidates
+            .into_iter()
+            .map(|(residue, modulus)| {
+                if residue >= min_step {
+                    residue
+                } else {
+                    residue + (min_step - residue + modulus - 1) / modulus * modulus
+                }
+            })
+            .min()
+            .map(|step| step as u64)
+    }
+
+    fn find_cycle(&self, start: &str) -> DesertMapCycle {
+        let mut seen: HashMap<(usize, &str), u64> = HashMap::new();
+        let mut hits: Vec<u64> = vec![];
+        let mut current: &str = start;
+        let mut step = 0u64;
+        loop {
+            let i = step as usize % self.instructions.len();
+            if let Some(first_seen) = seen.insert((i, current), step) {
+                let (prefix_hits, cycle_hits) = hits.into_iter().partition(|h| *h < first_seen);
+                return DesertMapCycle {
+                    offset: first_seen,
+                    period: step - first_seen,
+                    prefix_hits,
+                    cycle_hits,
+                };
+            }
+            if current.ends_with('Z') {
+                hits.push(step);
+            }
+            current = self.get_next(current, &self.instructions[i]);
+            step += 1;
+        }
+    }
+
+    fn get_next(&self, current: &str, dir: &CardinalDirection) -> &String {
+        let (left, right) = self.nodes.get(current).unwrap();
+        match dir {
+            CardinalDirection::East => right,
+            CardinalDirection::West => left,
+            _ => panic!("Unexpected direction!"),
+        }
+    }
+}
+
+/// Combines `x ≡ a (mod n)` and `x ≡ b (mod m)` into a single congruence, if one exists. The
+/// moduli do not need to be coprime.
+fn crt((a, n): (i128, i128), (b, m): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(n, m);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let lcm = n / g * m;
+    let k = ((b - a) / g * p).rem_euclid(m / g);
+    Some(((a + n * k).rem_euclid(lcm), lcm))
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+impl DesertMap {
+    /// Reads the instruction line, a blank line, then one node per line like `AAA = (BBB, CCC)`.
+    fn parse(s: &mut Scanner) -> Result<Self, InputError> {
+        let mut instructions = vec![];
+        // An empty instruction line would leave nothing to follow, so at least one is required
+        while instructions.is_empty() || !s.is_at_line_end() {
+            // Only left and right lead anywhere from a node
+            let instruction = DirectionAlphabet::UpDownLeftRight
+                .parse_char(s.one_of("LR")?)
+                .expect("L and R are both directions");
+            instructions.push(instruction);
+        }
+        s.line_end()?;
+        s.line_end()?;
+        let mut nodes = HashMap::new();
+        for (key, left, right) in s.lines(DesertMap::parse_node)? {
+            nodes.insert(key, (left, right));
+        }
+
+        Ok(DesertMap {
+            instructions,
+            nodes,
+        })
+    }
+
+    fn parse_node(s: &mut Scanner) -> Result<(String, String, String), ParseError> {
+        let key = s.word()?.to_owned();
+        s.literal("=")?;
+        s.literal("(")?;
+        let left = s.word()?.to_owned();
+        s.literal(",")?;
+        let right = s.word()?.to_owned();
+        s.literal(")")?;
+        Ok((key, left, right))
+    }
+}
+
+impl FromStr for DesertMap {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DesertMap::parse(&mut Scanner::new(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::desert_map::DesertMap;
+    use aoc_core::num::LeastCommonMultiple;
+
+    /// The answer the puzzle input allows for: the LCM of each ghost's loop length.
+    fn lcm_of_periods(map: &DesertMap) -> u64 {
+        map.nodes
+            .keys()
+            .filter(|x| x.ends_with('A'))
+            .map(|x| map.find_cycle(x).period)
+            .fold(1, |acc, val| acc.lcm(&val))
+    }
+
+    const EXAMPLE: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+
+    /// Ghost 1 loops through 11B, 11Z, 11C (period 3, 'Z' at step 2) and ghost 2 loops through
+    /// 22B, 22Z (period 2, 'Z' at step 2). The LCM of the periods is 6, but both ghosts first
+    /// stand on 'Z' together at step 2.
+    const OFFSET_MAP: &str = r#"L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11B, 11B)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)"#;
+
+    /// Ghost 1 only ever reaches 'Z' before entering its loop, at step 1. Ghost 2 reaches 'Z'
+    /// on every step from then on.
+    const PREFIX_MAP: &str = r#"L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22Z, 22Z)"#;
+
+    /// Ghost 1 is on 'Z' on odd steps and ghost 2 only on even steps.
+    const UNREACHABLE_MAP: &str = r#"L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)"#;
+
+    #[test]
+    fn navigate_ghost_example() {
+        let map: DesertMap = EXAMPLE.parse().unwrap();
+        assert_eq!(map.navigate_ghost(), Some(6));
+        assert_eq!(lcm_of_periods(&map), 6);
+    }
+
+    #[test]
+    fn navigate_ghost_with_offsets() {
+        let map: DesertMap = OFFSET_MAP.parse().unwrap();
+        assert_eq!(lcm_of_periods(&map), 6);
+        assert_eq!(map.navigate_ghost(), Some(2));
+    }
+
+    #[test]
+    fn navigate_ghost_before_loop() {
+        let map: DesertMap = PREFIX_MAP.parse().unwrap();
+        assert_eq!(map.navigate_ghost(), Some(1));
+    }
+
+    #[test]
+    fn navigate_ghost_unreachable() {
+        let map: DesertMap = UNREACHABLE_MAP.parse().unwrap();
+        assert_eq!(map.navigate_ghost(), None);
+    }
+
+    #[test]
+    fn rejects_unknown_instructions() {
+        let error = "LRX\n\nAAA = (AAA, AAA)"
+            .parse::<DesertMap>()
+            .err()
+            .unwrap();
+        assert_eq!((error.errors[0].line, error.errors[0].column), (1, 3));
+    }
+
+    #[test]
+    fn rejects_empty_instructions() {
+        let error = "\n\nAAA = (AAA, AAA)".parse::<DesertMap>().err().unwrap();
+        assert_eq!((error.errors[0].line, error.errors[0].column), (1, 1));
+        assert_eq!(error.errors[0].found, "end of line");
+    }
+
+    #[test]
+    fn reports_every_bad_node() {
+        let input = "LR\n\nAAA = (AAA AAA)\nBBB = (AAA, BBB)\nCCC (AAA, BBB)";
+        let error = input.parse::<DesertMap>().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "2 bad lines in puzzle input\n  \
+             line 3, column 12: expected \",\", found \"AAA)\"\n  \
+             line 5, column 5: expected \"=\", found \"(AAA,\""
+        );
+    }
+}
@@ -0,0 +1,43 @@
+mod desert_map;
+
+use crate::desert_map::DesertMap;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day08;
+
+impl Solution for Day08 {
+    type Input = DesertMap;
+    const PART_ONE: &'static str = "Steps to get from AAA to ZZZ as human";
+    const PART_TWO: &'static str = "Steps to get from ??A to ??Z as human";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        input.parse()
+    }
+
+    fn part_one(map: &Self::Input) -> impl Display {
+        map.navigate()
+    }
+
+    fn part_two(map: &Self::Input) -> impl Display {
+        map.navigate_ghost()
+            .expect("Ghosts never reach ??Z at the same time!")
+    }
+}
+
+fn main() {
+    run::<Day08>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day08>();
+    }
+}
@@ -0,0 +1,42 @@
+mod oasis;
+
+use crate::oasis::OasisReport;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day09;
+
+impl Solution for Day09 {
+    type Input = OasisReport;
+    const PART_ONE: &'static str = "Sum of extrapolated next values";
+    const PART_TWO: &'static str = "Sum of extrapolated previous values";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        input.parse()
+    }
+
+    fn part_one(oasis_report: &Self::Input) -> impl Display {
+        oasis_report.sum_next_values()
+    }
+
+    fn part_two(oasis_report: &Self::Input) -> impl Display {
+        oasis_report.sum_previous_values()
+    }
+}
+
+fn main() {
+    run::<Day09>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day09>();
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_core::scanner::{InputError, ParseError, Scanner};
+use std::str::FromStr;
+
+struct OasisItem {
+    history: Vec<i32>,
+}
+
+impl OasisItem {
+    fn extrapolate_next(&self) -> i32 {
+        let differences = self.compute_difference_list();
+        let mut value = 0i32;
+        for diff in differences.iter().rev() {
+            value += diff.last().unwrap();
+        }
+        value
+    }
+
+    fn extrapolate_previous(&self) -> i32 {
+        let differences = self.compute_difference_list();
+        let mut value = 0i32;
+        for diff in differences.iter().rev() {
+            value = diff.first().unwrap() - value;
+        }
+        value
+    }
+
+    fn compute_difference_list(&self) -> Vec<Vec<i32>> {
+        let mut result = vec![self.history.clone()];
+        while result.last().unwrap().iter().any(|x| *x != 0) {
+            let next = OasisItem::compute_differences(result.last().unwrap());
+            result.push(next);
+        }
+
+        result
+    }
+
+    fn compute_differences(source: &[i32]) -> Vec<i32> {
+        source
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect()
+    }
+}
+
+impl OasisItem {
+    /// Reads a line of space-separated readings. There must be at least one.
+    fn parse(s: &mut Scanner) -> Result<Self, ParseError> {
+        let mut history = vec![s.signed()?];
+        history.extend(s.many(|s| s.signed::<i32>()));
+
+        Ok(OasisItem { history })
+    }
+}
+
+pub struct OasisReport {
+    items: Vec<OasisItem>,
+}
+
+impl OasisReport {
+    pub fn sum_next_values(&self) -> i32 {
+        self.items.iter().map(|x| x.extrapolate_next()).sum()
+    }
+
+    pub fn sum_previous_values(&self) -> i32 {
+        self.items.iter().map(|x| x.extrapolate_previous()).sum()
+    }
+}
+
+impl FromStr for OasisReport {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items = Scanner::new(s).lines(OasisItem::parse)?;
+
+        Ok(OasisReport { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oasis::OasisReport;
+
+    #[test]
+    fn from_str() {
+        let report: OasisReport = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n"
+            .parse()
+            .ok()
+            .unwrap();
+        assert_eq!(report.sum_next_values(), 114);
+        assert_eq!(report.sum_previous_values(), 2);
+        let report: OasisReport = "-4 -1 2".parse().ok().unwrap();
+        assert_eq!(report.sum_next_values(), 5);
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let error = "0 3 6\n\n1 x 3\n4 5 6"
+            .parse::<OasisReport>()
+            .err()
+            .unwrap();
+        let positions: Vec<_> = error.errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(positions, vec![(2, 1), (3, 3)]);
+    }
+}
@@ -0,0 +1,44 @@
+mod pipe_complex;
+
+use crate::pipe_complex::PipeComplex;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+struct Day10;
+
+impl Solution for Day10 {
+    type Input = PipeComplex;
+    const PART_ONE: &'static str = "Midpoint of path";
+    const PART_TWO: &'static str = "Tiles inside loop";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        let mut pipe_complex: PipeComplex = input.parse()?;
+        pipe_complex.mark_outside();
+        Ok(pipe_complex)
+    }
+
+    fn part_one(pipe_complex: &Self::Input) -> impl Display {
+        pipe_complex.get_midpoint()
+    }
+
+    fn part_two(pipe_complex: &Self::Input) -> impl Display {
+        pipe_complex.count_unmarked()
+    }
+}
+
+fn main() {
+    run::<Day10>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    fn matches_recorded_answers() {
+        check_answers::<Day10>();
+    }
+}
@@ -1,8 +1,10 @@
 use aoc_core::cardinal_direction::CardinalDirection;
+use aoc_core::grid_view::{Cell, Colour, Grid, GridView, Style};
 use aoc_core::point::Point as PointCore;
+use aoc_core::scanner::{InputError, ParseError, Scanner};
 use std::collections::HashSet;
-use std::fmt::{Display, Formatter, Write};
-use std::str::Lines;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 type Point = PointCore<usize>;
 
@@ -33,7 +35,7 @@ impl PipeTile {
         }
     }
 
-    fn to_char(&self, is_path: bool) -> char {
+    fn to_char(self, is_path: bool) -> char {
         if is_path {
             match self {
                 PipeTile::Vertical => '┃',
@@ -54,7 +56,7 @@ impl PipeTile {
                 PipeTile::BendSouthEast => '┌',
                 PipeTile::BendSouthWest => '┐',
                 PipeTile::Ground => ' ',
-                _ => panic!("Character should never be outside path"),
+                PipeTile::Start => 'S',
             }
         }
     }
@@ -102,7 +104,7 @@ impl PipeComplex {
 
     pub fn count_unmarked(&self) -> usize {
         self.iter_points()
-            .filter(|x| !self.marked.contains(&x))
+            .filter(|x| !self.marked.contains(x))
             .count()
     }
 
@@ -152,7 +154,7 @@ impl PipeComplex {
 
     fn trace_path(&self) -> Vec<Point> {
         let start = self.get_start();
-        let mut path: Vec<Point> = vec![start.clone()];
+        let mut path: Vec<Point> = vec![start];
         // Arbitrarily pick a starting direction, it doesn't matter.
         path.push(self.get_exits(&start).0);
         loop {
@@ -172,7 +174,7 @@ impl PipeComplex {
     }
 
     fn get_exits(&self, point: &Point) -> (Point, Point) {
-        let tile = self.get_tile(&point);
+        let tile = self.get_tile(point);
         let (dir_a, dir_b) = tile.get_directions();
         let point_a = point.go(&dir_a);
         let point_b = point.go(&dir_b);
@@ -218,39 +220,57 @@ impl PipeComplex {
     }
 }
 
-impl From<Lines<'_>> for PipeComplex {
-    fn from(value: Lines<'_>) -> Self {
-        let mut map: Vec<Vec<PipeTile>> = vec![];
-        for line in value.into_iter() {
-            let mut row = vec![];
-            for char in line.chars() {
-                row.push(char.into());
-            }
-            map.push(row);
+impl PipeComplex {
+    fn parse_row(s: &mut Scanner) -> Result<Vec<PipeTile>, ParseError> {
+        let mut row = vec![];
+        while !s.is_at_line_end() {
+            row.push(s.one_of("|-LJF7.S")?.into());
         }
+        Ok(row)
+    }
+}
 
-        PipeComplex {
+impl FromStr for PipeComplex {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Scanner::new(s).lines(PipeComplex::parse_row)?;
+
+        Ok(PipeComplex {
             map,
             marked: HashSet::new(),
-        }
+        })
+    }
+}
+
+impl Grid for PipeComplex {
+    fn width(&self) -> usize {
+        self.map.first().map_or(0, Vec::len)
+    }
+
+    fn height(&self) -> usize {
+        self.map.len()
+    }
+
+    fn cell(&self, point: Point) -> Cell {
+        Cell::plain(self.get_tile(&point).to_char(false))
     }
 }
 
+/// Draws the loop in heavy lines and marked tiles off the loop as `X`. With `{:#}` these are
+/// coloured as well.
 impl Display for PipeComplex {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let path = self.trace_path();
-        for (y, line) in self.map.iter().enumerate() {
-            for (x, tile) in line.iter().enumerate() {
-                let point = Point { x, y };
-                if self.marked.contains(&point) && !path.contains(&point) {
-                    f.write_char('X')?;
-                } else {
-                    f.write_char(tile.to_char(path.contains(&point)))?;
-                }
-            }
-            f.write_char('\n')?;
+        let path: HashSet<Point> = self.trace_path().into_iter().collect();
+        let mut view = GridView::new(self).colour(f.alternate());
+        for point in path.iter() {
+            let glyph = self.get_tile(point).to_char(true);
+            view = view.mark(*point, glyph, Style::fg(Colour::Yellow).bold());
+        }
+        for point in self.marked.difference(&path) {
+            view = view.mark(*point, 'X', Style::fg(Colour::Red).dim());
         }
-        Ok(())
+        view.fmt(f)
     }
 }
 
@@ -270,9 +290,30 @@ mod tests {
 
     #[test]
     fn test_inside() {
-        let mut pipes: PipeComplex = INPUT.lines().into();
+        let mut pipes: PipeComplex = INPUT.parse().unwrap();
         pipes.mark_outside();
         print!("{}", pipes);
         assert_eq!(pipes.count_unmarked(), 4);
     }
+
+    #[test]
+    fn draws_loop_and_marks() {
+        let mut pipes: PipeComplex = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF".parse().unwrap();
+        assert_eq!(pipes.to_string(), "─└│┌┐\n┐S━┓│\n└┃┐┃│\n─┗━┛│\n└│─┘┌\n");
+        pipes.mark_outside();
+        assert_eq!(pipes.to_string(), "XXXXX\nXS━┓X\nX┃┐┃X\nX┗━┛X\nXXXXX\n");
+        let coloured = format!("{:#}", pipes);
+        assert!(coloured.starts_with("\x1b[2;31mXXXXX\x1b[0m\n\x1b[2;31mX\x1b[0m\x1b[1;33mS━┓"));
+    }
+
+    #[test]
+    fn reports_every_bad_tile() {
+        let error = "..\n.X\n..\nQ.".parse::<PipeComplex>().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "2 bad lines in puzzle input\n  \
+             line 2, column 2: expected one of |-LJF7.S, found \"X\"\n  \
+             line 4, column 1: expected one of |-LJF7.S, found \"Q\""
+        );
+    }
 }
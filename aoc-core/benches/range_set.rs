@@ -0,0 +1,108 @@
+//! Compares the `RangeSet` set operations against the nested-loop versions they replaced.
+//!
+//! Run with `cargo bench -p aoc-core --bench range_set`.
+
+use aoc_core::set::range_set::RangeSet;
+use aoc_core::set::{SetDifference, SetIntersection, SetUnion};
+use std::hint::black_box;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+/// Large enough to show the difference, small enough that the quadratic versions still finish.
+const NAIVE_SIZE: usize = 5_000;
+const SWEEP_SIZE: usize = 50_000;
+
+/// Builds `count` disjoint ranges with pseudo-random gaps and lengths, so that two sets built
+/// from different seeds interleave and partially overlap.
+fn build_set(count: usize, seed: u64) -> RangeSet<u64> {
+    let mut state = seed;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) % 100
+    };
+    let mut start = 0u64;
+    let mut ranges = Vec::with_capacity(count);
+    for _ in 0..count {
+        start += next() + 1;
+        let end = start + next();
+        ranges.push(start..=end);
+        start = end + 1;
+    }
+    RangeSet::from_inclusive(ranges)
+}
+
+fn naive_intersection(lhs: &RangeSet<u64>, rhs: &RangeSet<u64>) -> RangeSet<u64> {
+    let mut result = vec![];
+    for l in lhs.iter_inclusive() {
+        for r in rhs.iter_inclusive() {
+            let start = *l.start().max(r.start());
+            let end = *l.end().min(r.end());
+            if start <= end {
+                result.push(start..=end);
+            }
+        }
+    }
+    RangeSet::from_inclusive(result)
+}
+
+fn naive_difference(lhs: &RangeSet<u64>, rhs: &RangeSet<u64>) -> RangeSet<u64> {
+    let result = rhs.iter_inclusive().fold(
+        lhs.iter_inclusive().collect::<Vec<_>>(),
+        |acc, r: RangeInclusive<u64>| {
+            let mut step_result = vec![];
+            for l in acc {
+                if l.end() < r.start() || l.start() > r.end() {
+                    step_result.push(l);
+                } else {
+                    if l.start() < r.start() {
+                        step_result.push(*l.start()..=*r.start() - 1);
+                    }
+                    if l.end() > r.end() {
+                        step_result.push(*r.end() + 1..=*l.end());
+                    }
+                }
+            }
+            step_result
+        },
+    );
+    RangeSet::from_inclusive(result)
+}
+
+fn time<F: FnMut() -> RangeSet<u64>>(mut f: F) -> (Duration, RangeSet<u64>) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (start.elapsed(), result)
+}
+
+fn report(name: &str, size: usize, elapsed: Duration) {
+    println!("{:<24} {:>7} ranges {:>12.3?}", name, size, elapsed);
+}
+
+fn main() {
+    let lhs = build_set(NAIVE_SIZE, 1);
+    let rhs = build_set(NAIVE_SIZE, 2);
+
+    let (naive, expected) = time(|| naive_intersection(&lhs, &rhs));
+    let (sweep, actual) = time(|| lhs.intersection(&rhs));
+    assert_eq!(actual, expected);
+    report("naive intersection", NAIVE_SIZE, naive);
+    report("intersection", NAIVE_SIZE, sweep);
+
+    let (naive, expected) = time(|| naive_difference(&lhs, &rhs));
+    let (sweep, actual) = time(|| lhs.difference(&rhs));
+    assert_eq!(actual, expected);
+    report("naive difference", NAIVE_SIZE, naive);
+    report("difference", NAIVE_SIZE, sweep);
+
+    let lhs = build_set(SWEEP_SIZE, 1);
+    let rhs = build_set(SWEEP_SIZE, 2);
+    report(
+        "intersection",
+        SWEEP_SIZE,
+        time(|| lhs.intersection(&rhs)).0,
+    );
+    report("difference", SWEEP_SIZE, time(|| lhs.difference(&rhs)).0);
+    report("union", SWEEP_SIZE, time(|| lhs.union(&rhs)).0);
+}
@@ -0,0 +1,134 @@
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Known-good answers for a day, kept in an `answers.txt` next to its `input.txt`. Part one is on
+/// the first line and part two on the second; a part that hasn't been solved yet is left blank.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn new(part_one: impl Display, part_two: impl Display) -> Self {
+        Answers {
+            part_one: Some(part_one.to_string()),
+            part_two: Some(part_two.to_string()),
+        }
+    }
+
+    /// The `answers.txt` in the directory of the currently executing crate.
+    pub fn default_path() -> PathBuf {
+        let base_path = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        [&base_path, "answers.txt"].iter().collect()
+    }
+
+    pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Answers::from(read_to_string(path)?.as_str()))
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        write(path, self.to_string())
+    }
+
+    /// Lists every recorded answer that `actual` disagrees with. Parts without a recorded answer
+    /// are not checked.
+    pub fn mismatches(&self, actual: &Answers) -> Vec<Mismatch> {
+        [
+            (1, &self.part_one, &actual.part_one),
+            (2, &self.part_two, &actual.part_two),
+        ]
+        .into_iter()
+        .filter_map(|(part, expected, actual)| {
+            let expected = expected.as_ref()?;
+            (Some(expected) != actual.as_ref()).then(|| Mismatch {
+                part,
+                expected: expected.clone(),
+                actual: actual.clone(),
+            })
+        })
+        .collect()
+    }
+}
+
+impl From<&str> for Answers {
+    fn from(value: &str) -> Self {
+        let mut lines = value
+            .lines()
+            .map(str::trim)
+            .map(|line| (!line.is_empty()).then(|| line.to_owned()));
+        Answers {
+            part_one: lines.next().flatten(),
+            part_two: lines.next().flatten(),
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{}\n{}\n",
+            self.part_one.as_deref().unwrap_or_default(),
+            self.part_two.as_deref().unwrap_or_default()
+        ))
+    }
+}
+
+/// A part whose answer no longer matches the recorded one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    pub part: u8,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "part {}: expected {}, got {}",
+            self.part,
+            self.expected,
+            self.actual.as_deref().unwrap_or("nothing")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answers, Mismatch};
+
+    #[test]
+    fn round_trip() {
+        let answers = Answers::new(55208, "abc");
+        assert_eq!(answers.to_string(), "55208\nabc\n");
+        assert_eq!(Answers::from(answers.to_string().as_str()), answers);
+
+        let partial = Answers::from("12\n");
+        assert_eq!(partial.part_one.as_deref(), Some("12"));
+        assert_eq!(partial.part_two, None);
+        assert_eq!(Answers::from("\n34").part_one, None);
+    }
+
+    #[test]
+    fn mismatches() {
+        let recorded = Answers::from("1\n2\n");
+        assert!(recorded.mismatches(&Answers::new(1, 2)).is_empty());
+        assert_eq!(
+            recorded.mismatches(&Answers::new(1, 3)),
+            vec![Mismatch {
+                part: 2,
+                expected: String::from("2"),
+                actual: Some(String::from("3")),
+            }]
+        );
+        assert_eq!(
+            recorded.mismatches(&Answers::new(3, 2))[0].to_string(),
+            "part 1: expected 1, got 3"
+        );
+
+        let partial = Answers::from("1\n");
+        assert!(partial.mismatches(&Answers::new(1, 99)).is_empty());
+    }
+}
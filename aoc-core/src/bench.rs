@@ -0,0 +1,123 @@
+use crate::puzzle_input::PuzzleInput;
+use crate::scanner::InputError;
+use crate::solution::Solution;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::ops::AddAssign;
+use std::time::{Duration, Instant};
+
+/// Roughly how long each stage is repeated for, so that fast stages get a stable average.
+const TARGET: Duration = Duration::from_millis(200);
+const MAX_RUNS: u128 = 10_000;
+
+/// How long each stage of a [Solution] takes, averaged over a number of runs. Reading
+/// `input.txt` from disk is not included.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    pub fn measure<S: Solution>(input: &PuzzleInput) -> Result<Self, InputError> {
+        let parsed = S::parse(input)?;
+        Ok(Timings {
+            parse: average(|| S::parse(input)),
+            part_one: average(|| S::part_one(&parsed)),
+            part_two: average(|| S::part_two(&parsed)),
+        })
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+
+    /// Writes the timings as nanoseconds on a single line, to pass them between processes.
+    pub fn to_record(&self) -> String {
+        format!(
+            "timings {} {} {}",
+            self.parse.as_nanos(),
+            self.part_one.as_nanos(),
+            self.part_two.as_nanos()
+        )
+    }
+
+    /// Reads a line written by [Timings::to_record].
+    pub fn from_record(line: &str) -> Option<Self> {
+        let mut nanos = line.strip_prefix("timings ")?.split(' ');
+        let mut next =
+            || -> Option<Duration> { Some(Duration::from_nanos(nanos.next()?.parse().ok()?)) };
+        let timings = Timings {
+            parse: next()?,
+            part_one: next()?,
+            part_two: next()?,
+        };
+        next().is_none().then_some(timings)
+    }
+}
+
+impl AddAssign for Timings {
+    fn add_assign(&mut self, rhs: Self) {
+        self.parse += rhs.parse;
+        self.part_one += rhs.part_one;
+        self.part_two += rhs.part_two;
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("parse    {:>12.3?}\n", self.parse))?;
+        f.write_fmt(format_args!("part one {:>12.3?}\n", self.part_one))?;
+        f.write_fmt(format_args!("part two {:>12.3?}\n", self.part_two))?;
+        f.write_fmt(format_args!("total    {:>12.3?}", self.total()))
+    }
+}
+
+/// Runs `f` repeatedly for about [TARGET] and returns the mean time of a single run. Stages that
+/// take longer than that on their own are only run once.
+fn average<T>(mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    let first = start.elapsed();
+    if first >= TARGET {
+        return first;
+    }
+
+    let runs = (TARGET.as_nanos() / first.as_nanos().max(1)).clamp(1, MAX_RUNS) as u32;
+    let start = Instant::now();
+    for _ in 0..runs {
+        black_box(f());
+    }
+    start.elapsed() / runs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{average, Timings};
+    use std::time::Duration;
+
+    #[test]
+    fn record_round_trip() {
+        let timings = Timings {
+            parse: Duration::from_nanos(1_500),
+            part_one: Duration::from_millis(3),
+            part_two: Duration::from_secs(2),
+        };
+        let record = timings.to_record();
+        assert_eq!(record, "timings 1500 3000000 2000000000");
+        assert_eq!(Timings::from_record(&record), Some(timings));
+        assert_eq!(timings.total(), Duration::from_nanos(2_003_001_500));
+
+        assert_eq!(Timings::from_record("timings 1 2"), None);
+        assert_eq!(Timings::from_record("timings 1 2 3 4"), None);
+        assert_eq!(Timings::from_record("Part one: 12"), None);
+    }
+
+    #[test]
+    fn average_repeats_fast_stages() {
+        let mut runs = 0;
+        average(|| runs += 1);
+        assert!(runs > 1);
+    }
+}
@@ -0,0 +1,77 @@
+//! Times every day that has an `input.txt` and prints a year-wide total.
+//!
+//! Run with `cargo run --release -p aoc-core --bin bench -- [--year <year>]`, where the year
+//! defaults to the latest one in the workspace. The days are built first, in the same profile as
+//! this binary, and each is run with `--bench --record`.
+
+use aoc_core::bench::Timings;
+use aoc_core::workspace;
+use std::env;
+use std::env::consts::EXE_SUFFIX;
+use std::process::{exit, Command};
+
+fn main() {
+    let root = workspace::root();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let Some(year) = workspace::take_year(&mut args, root).filter(|_| args.is_empty()) else {
+        eprintln!("Usage: bench [--year <year>]");
+        exit(2);
+    };
+    if !root.join(year.to_string()).is_dir() {
+        eprintln!("There are no days for {}", year);
+        exit(1);
+    }
+    let exe = env::current_exe().unwrap();
+    let bin_dir = exe.parent().unwrap();
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let mut build = Command::new(cargo);
+    build
+        .current_dir(root)
+        .args(["build", "--workspace", "--bins"]);
+    if bin_dir.ends_with("release") {
+        build.arg("--release");
+    }
+    if !build.status().is_ok_and(|s| s.success()) {
+        eprintln!("Failed to build the days");
+        exit(1);
+    }
+
+    println!(
+        "{:<6} {:>12} {:>12} {:>12} {:>12}",
+        "day", "parse", "part one", "part two", "total"
+    );
+    let mut total = Timings::default();
+    for day in 1..=25 {
+        let name = format!("aoc-{:02}", day);
+        let dir = workspace::day_dir(root, year, day);
+        let day_exe = bin_dir.join(workspace::package_name(year, day) + EXE_SUFFIX);
+        if !dir.join("input.txt").exists() || !day_exe.exists() {
+            continue;
+        }
+        let output = Command::new(&day_exe)
+            .args(["--bench", "--record"])
+            .env("CARGO_MANIFEST_DIR", &dir)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        // Days that haven't been solved yet don't report any timings
+        let Some(timings) = stdout.lines().find_map(Timings::from_record) else {
+            continue;
+        };
+        print_row(&name, &timings);
+        total += timings;
+    }
+    print_row(&year.to_string(), &total);
+}
+
+fn print_row(name: &str, timings: &Timings) {
+    println!(
+        "{:<6} {:>12.3?} {:>12.3?} {:>12.3?} {:>12.3?}",
+        name,
+        timings.parse,
+        timings.part_one,
+        timings.part_two,
+        timings.total()
+    );
+}
@@ -0,0 +1,52 @@
+//! Downloads the puzzle inputs for the given days into `YYYY/aoc-NN/input.txt`, skipping any that
+//! are already there.
+//!
+//! Run with `cargo run -p aoc-core --bin fetch -- [--year <year>] 11 12`. The year defaults to the
+//! latest one in the workspace. The session cookie is read from the `AOC_SESSION` environment
+//! variable, or from a `.session` file in the root of the repo.
+
+use aoc_core::fetch::{find_session, Fetcher};
+use aoc_core::workspace;
+use std::env;
+use std::process::exit;
+
+fn main() {
+    let root = workspace::root();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let year = workspace::take_year(&mut args, root).unwrap_or_else(|| usage());
+    let days: Vec<u8> = args
+        .iter()
+        .map(|arg| arg.parse().ok().filter(|day| (1..=25).contains(day)))
+        .collect::<Option<_>>()
+        .unwrap_or_else(|| usage());
+    if days.is_empty() {
+        usage();
+    }
+
+    let Some(session) = find_session(root) else {
+        eprintln!("Set AOC_SESSION or write the session cookie to .session");
+        exit(1);
+    };
+    let mut fetcher = Fetcher::new(year, &session, root);
+    let mut failed = false;
+    for day in days {
+        let name = format!("{}/aoc-{:02}", year, day);
+        let cached = fetcher.is_cached(day);
+        match fetcher.input(day) {
+            Ok(_) if cached => println!("{}: already cached", name),
+            Ok(input) => println!("{}: downloaded {} lines", name, input.lines().count()),
+            Err(e) => {
+                eprintln!("{}: {}", name, e);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: fetch [--year <year>] <day>... (days are 1 to 25)");
+    exit(2);
+}
@@ -0,0 +1,42 @@
+//! Creates the `YYYY/aoc-NN` crate for a day, ready to be solved, and makes sure the workspace
+//! includes its year.
+//!
+//! Run with `cargo run -p aoc-core --bin scaffold -- [--year <year>] <day> [model]`, where the year
+//! defaults to the latest one in the workspace and `model` is the snake case name of the module
+//! holding the day's types (`puzzle` if left out). Files that already hold work are never
+//! overwritten.
+
+use aoc_core::scaffold::Scaffold;
+use aoc_core::workspace;
+use std::env;
+use std::process::exit;
+
+fn main() {
+    let root = workspace::root();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let year = workspace::take_year(&mut args, root).unwrap_or_else(|| usage());
+    let (day, model) = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [day] => (day, "puzzle"),
+        [day, model] => (day, model),
+        _ => usage(),
+    };
+    let day = day.parse().unwrap_or_else(|_| usage());
+
+    let written = Scaffold::new(year, day, model)
+        .and_then(|scaffold| scaffold.create(root))
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(1);
+        });
+    for path in written {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(root).unwrap_or(&path).display()
+        );
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: scaffold [--year <year>] <day> [model]");
+    exit(2);
+}
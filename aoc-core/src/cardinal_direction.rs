@@ -1,3 +1,8 @@
+use crate::direction::Heading;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum CardinalDirection {
     North,
@@ -6,18 +11,257 @@ pub enum CardinalDirection {
     West,
 }
 
-impl From<char> for CardinalDirection {
-    fn from(input: char) -> Self {
-        match input.to_lowercase().next().unwrap() {
-            'n' => CardinalDirection::North,
-            's' => CardinalDirection::South,
-            'e' => CardinalDirection::East,
-            'w' => CardinalDirection::West,
-            'u' => CardinalDirection::North,
-            'd' => CardinalDirection::South,
-            'r' => CardinalDirection::East,
-            'l' => CardinalDirection::West,
-            _a => panic!("Char {} cannot be converted to a cardinal direction!", _a),
+impl CardinalDirection {
+    /// All four cardinal directions, clockwise from north.
+    pub const ALL: [CardinalDirection; 4] = [
+        CardinalDirection::North,
+        CardinalDirection::East,
+        CardinalDirection::South,
+        CardinalDirection::West,
+    ];
+
+    /// Returns the direction 90° counter-clockwise from `self`.
+    pub fn turn_left(&self) -> Self {
+        match self {
+            CardinalDirection::North => CardinalDirection::West,
+            CardinalDirection::West => CardinalDirection::South,
+            CardinalDirection::South => CardinalDirection::East,
+            CardinalDirection::East => CardinalDirection::North,
+        }
+    }
+
+    /// Returns the direction 90° clockwise from `self`.
+    pub fn turn_right(&self) -> Self {
+        match self {
+            CardinalDirection::North => CardinalDirection::East,
+            CardinalDirection::East => CardinalDirection::South,
+            CardinalDirection::South => CardinalDirection::West,
+            CardinalDirection::West => CardinalDirection::North,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            CardinalDirection::North => CardinalDirection::South,
+            CardinalDirection::South => CardinalDirection::North,
+            CardinalDirection::East => CardinalDirection::West,
+            CardinalDirection::West => CardinalDirection::East,
+        }
+    }
+}
+
+impl Heading for CardinalDirection {
+    fn to_delta(&self) -> (i8, i8) {
+        match self {
+            CardinalDirection::North => (0, -1),
+            CardinalDirection::South => (0, 1),
+            CardinalDirection::East => (1, 0),
+            CardinalDirection::West => (-1, 0),
+        }
+    }
+}
+
+/// The set of characters accepted when parsing a [CardinalDirection]. Puzzles use different
+/// alphabets for directions, and some letters mean different things in each (`L` is west in
+/// `UDLR`, but is not a compass letter), so callers pick the one their input uses.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DirectionAlphabet {
+    /// `N`, `S`, `E` and `W`.
+    Compass,
+    /// `U`, `D`, `L` and `R`.
+    UpDownLeftRight,
+    /// `^`, `v`, `<` and `>`.
+    Arrows,
+}
+
+impl DirectionAlphabet {
+    pub const ALL: [DirectionAlphabet; 3] = [
+        DirectionAlphabet::Compass,
+        DirectionAlphabet::UpDownLeftRight,
+        DirectionAlphabet::Arrows,
+    ];
+
+    /// Parses a single character as a direction in this alphabet. Matching is case-sensitive.
+    pub fn parse_char(&self, input: char) -> Result<CardinalDirection, ParseDirectionError> {
+        let dir = match (self, input) {
+            (DirectionAlphabet::Compass, 'N') => CardinalDirection::North,
+            (DirectionAlphabet::Compass, 'S') => CardinalDirection::South,
+            (DirectionAlphabet::Compass, 'E') => CardinalDirection::East,
+            (DirectionAlphabet::Compass, 'W') => CardinalDirection::West,
+            (DirectionAlphabet::UpDownLeftRight, 'U') => CardinalDirection::North,
+            (DirectionAlphabet::UpDownLeftRight, 'D') => CardinalDirection::South,
+            (DirectionAlphabet::UpDownLeftRight, 'R') => CardinalDirection::East,
+            (DirectionAlphabet::UpDownLeftRight, 'L') => CardinalDirection::West,
+            (DirectionAlphabet::Arrows, '^') => CardinalDirection::North,
+            (DirectionAlphabet::Arrows, 'v') => CardinalDirection::South,
+            (DirectionAlphabet::Arrows, '>') => CardinalDirection::East,
+            (DirectionAlphabet::Arrows, '<') => CardinalDirection::West,
+            _ => {
+                return Err(ParseDirectionError {
+                    input: input.to_string(),
+                    position: None,
+                    alphabet: Some(*self),
+                })
+            }
+        };
+        Ok(dir)
+    }
+
+    /// Parses every character of `input` as a direction in this alphabet.
+    pub fn parse_str(&self, input: &str) -> Result<Vec<CardinalDirection>, ParseDirectionError> {
+        input
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                self.parse_char(c).map_err(|e| ParseDirectionError {
+                    position: Some(i),
+                    ..e
+                })
+            })
+            .collect()
+    }
+
+    fn symbols(&self) -> &'static str {
+        match self {
+            DirectionAlphabet::Compass => "NSEW",
+            DirectionAlphabet::UpDownLeftRight => "UDLR",
+            DirectionAlphabet::Arrows => "^v<>",
+        }
+    }
+}
+
+/// Returned when text does not describe a [CardinalDirection].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseDirectionError {
+    input: String,
+    /// The index of the offending character, when parsing a sequence of directions.
+    position: Option<usize>,
+    /// The alphabet that was being parsed, if the caller chose one.
+    alphabet: Option<DirectionAlphabet>,
+}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?} is not a cardinal direction", self.input))?;
+        if let Some(position) = self.position {
+            f.write_fmt(format_args!(" at position {}", position))?;
+        }
+        match self.alphabet {
+            Some(a) => f.write_fmt(format_args!(" (expected one of {})", a.symbols())),
+            None => f.write_str(" (expected a direction name or one of NSEW, UDLR, ^v<>)"),
+        }
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// Accepts a character from any [DirectionAlphabet]. No character belongs to more than one.
+impl TryFrom<char> for CardinalDirection {
+    type Error = ParseDirectionError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        DirectionAlphabet::ALL
+            .iter()
+            .find_map(|a| a.parse_char(value).ok())
+            .ok_or(ParseDirectionError {
+                input: value.to_string(),
+                position: None,
+                alphabet: None,
+            })
+    }
+}
+
+/// Accepts either a single character from any [DirectionAlphabet], or the name of a direction
+/// such as `North`.
+impl FromStr for CardinalDirection {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return CardinalDirection::try_from(c);
+        }
+        match s {
+            "North" => Ok(CardinalDirection::North),
+            "South" => Ok(CardinalDirection::South),
+            "East" => Ok(CardinalDirection::East),
+            "West" => Ok(CardinalDirection::West),
+            _ => Err(ParseDirectionError {
+                input: s.to_owned(),
+                position: None,
+                alphabet: None,
+            }),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::cardinal_direction::{CardinalDirection, DirectionAlphabet};
+
+    #[test]
+    fn turning() {
+        for dir in CardinalDirection::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.turn_left().turn_left(), dir.opposite());
+        }
+        assert_eq!(
+            CardinalDirection::North.turn_right(),
+            CardinalDirection::East
+        );
+        assert_eq!(
+            CardinalDirection::North.turn_left(),
+            CardinalDirection::West
+        );
+    }
+
+    #[test]
+    fn parse_char_per_alphabet() {
+        let udlr = DirectionAlphabet::UpDownLeftRight;
+        assert_eq!(udlr.parse_char('U'), Ok(CardinalDirection::North));
+        assert_eq!(udlr.parse_char('L'), Ok(CardinalDirection::West));
+        assert!(udlr.parse_char('N').is_err());
+        assert!(udlr.parse_char('u').is_err());
+
+        let compass = DirectionAlphabet::Compass;
+        assert_eq!(compass.parse_char('E'), Ok(CardinalDirection::East));
+        assert!(compass.parse_char('R').is_err());
+
+        let arrows = DirectionAlphabet::Arrows;
+        assert_eq!(arrows.parse_char('v'), Ok(CardinalDirection::South));
+        assert_eq!(arrows.parse_char('<'), Ok(CardinalDirection::West));
+        assert!(arrows.parse_char('V').is_err());
+    }
+
+    #[test]
+    fn parse_str_reports_position() {
+        let udlr = DirectionAlphabet::UpDownLeftRight;
+        assert_eq!(
+            udlr.parse_str("LR"),
+            Ok(vec![CardinalDirection::West, CardinalDirection::East])
+        );
+        let err = udlr.parse_str("LRX").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"X\" is not a cardinal direction at position 2 (expected one of UDLR)"
+        );
+    }
+
+    #[test]
+    fn try_from_and_from_str() {
+        assert_eq!(
+            CardinalDirection::try_from('^'),
+            Ok(CardinalDirection::North)
+        );
+        assert_eq!(
+            CardinalDirection::try_from('W'),
+            Ok(CardinalDirection::West)
+        );
+        assert!(CardinalDirection::try_from('x').is_err());
+        assert_eq!("R".parse(), Ok(CardinalDirection::East));
+        assert_eq!("South".parse(), Ok(CardinalDirection::South));
+        assert!("Up".parse::<CardinalDirection>().is_err());
+        assert!("".parse::<CardinalDirection>().is_err());
+    }
+}
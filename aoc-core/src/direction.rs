@@ -0,0 +1,128 @@
+use crate::cardinal_direction::CardinalDirection;
+use crate::ordinal_direction::OrdinalDirection;
+
+/// Describes a thing that points somewhere on a 2D grid, where north is towards smaller `y`.
+pub trait Heading {
+    /// Returns the `(x, y)` step taken when moving one unit towards `self`. Each component is
+    /// -1, 0 or 1.
+    fn to_delta(&self) -> (i8, i8);
+}
+
+/// Any of the eight directions to a neighbouring cell, cardinal or ordinal.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the direction 45° counter-clockwise from `self`.
+    pub fn turn_left(&self) -> Self {
+        Direction::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Returns the direction 45° clockwise from `self`.
+    pub fn turn_right(&self) -> Self {
+        Direction::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Direction::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_cardinal(&self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+
+    fn index(&self) -> usize {
+        Direction::ALL.iter().position(|d| d == self).unwrap()
+    }
+}
+
+impl Heading for Direction {
+    fn to_delta(&self) -> (i8, i8) {
+        match self {
+            Direction::North => CardinalDirection::North.to_delta(),
+            Direction::NorthEast => OrdinalDirection::NorthEast.to_delta(),
+            Direction::East => CardinalDirection::East.to_delta(),
+            Direction::SouthEast => OrdinalDirection::SouthEast.to_delta(),
+            Direction::South => CardinalDirection::South.to_delta(),
+            Direction::SouthWest => OrdinalDirection::SouthWest.to_delta(),
+            Direction::West => CardinalDirection::West.to_delta(),
+            Direction::NorthWest => OrdinalDirection::NorthWest.to_delta(),
+        }
+    }
+}
+
+impl From<CardinalDirection> for Direction {
+    fn from(value: CardinalDirection) -> Self {
+        match value {
+            CardinalDirection::North => Direction::North,
+            CardinalDirection::South => Direction::South,
+            CardinalDirection::East => Direction::East,
+            CardinalDirection::West => Direction::West,
+        }
+    }
+}
+
+impl From<OrdinalDirection> for Direction {
+    fn from(value: OrdinalDirection) -> Self {
+        match value {
+            OrdinalDirection::NorthEast => Direction::NorthEast,
+            OrdinalDirection::NorthWest => Direction::NorthWest,
+            OrdinalDirection::SouthEast => Direction::SouthEast,
+            OrdinalDirection::SouthWest => Direction::SouthWest,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cardinal_direction::CardinalDirection;
+    use crate::direction::{Direction, Heading};
+    use crate::ordinal_direction::OrdinalDirection;
+
+    #[test]
+    fn turning() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_ne!(dir.is_cardinal(), dir.turn_right().is_cardinal());
+        }
+        assert_eq!(Direction::North.turn_left(), Direction::NorthWest);
+        assert_eq!(Direction::NorthWest.turn_right(), Direction::North);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+    }
+
+    #[test]
+    fn matches_four_way_directions() {
+        for dir in CardinalDirection::ALL {
+            assert_eq!(Direction::from(dir).to_delta(), dir.to_delta());
+            assert_eq!(Direction::from(dir).opposite(), dir.opposite().into());
+            assert!(Direction::from(dir).is_cardinal());
+        }
+        for dir in OrdinalDirection::ALL {
+            assert_eq!(Direction::from(dir).to_delta(), dir.to_delta());
+            assert_eq!(Direction::from(dir).opposite(), dir.opposite().into());
+            assert!(!Direction::from(dir).is_cardinal());
+        }
+    }
+}
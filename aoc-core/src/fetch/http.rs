@@ -0,0 +1,251 @@
+use std::io;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+
+/// The parts of an HTTP response that fetching cares about.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests on behalf of a [Fetcher](crate::fetch::Fetcher) or
+/// [Submitter](crate::submit::Submitter), so that tests can swap the real site for a local
+/// stand-in.
+pub trait HttpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response>;
+
+    /// Posts `form` as `application/x-www-form-urlencoded`.
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response>;
+}
+
+/// Talks to the real site by running `curl`, which handles HTTPS.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Curl;
+
+impl Curl {
+    fn command() -> Command {
+        let mut command = Command::new("curl");
+        // Print the status code on a line of its own after the body
+        command.args(["--silent", "--show-error", "--write-out", "\n%{http_code}"]);
+        // Headers carry the session cookie, so they're read from stdin rather than passed as
+        // arguments, which any local user could see while curl runs
+        command.args(["--header", "@-"]);
+        command
+    }
+
+    fn run(mut command: Command, headers: &[(&str, &str)]) -> io::Result<Response> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        for (name, value) in headers {
+            writeln!(stdin, "{}: {}", name, value)?;
+        }
+        // curl only starts once it has read every header, i.e. once stdin is closed
+        drop(stdin);
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(io::Error::other(stderr.trim().to_owned()));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| invalid_data("curl printed no status code"))?;
+        Ok(Response {
+            status: status.parse().map_err(|_| invalid_data(status))?,
+            body: body.to_owned(),
+        })
+    }
+}
+
+impl HttpClient for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        let mut command = Curl::command();
+        command.arg(url);
+        Curl::run(command, headers)
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response> {
+        let mut command = Curl::command();
+        for (name, value) in form {
+            command
+                .arg("--data-urlencode")
+                .arg(format!("{}={}", name, value));
+        }
+        command.arg(url);
+        Curl::run(command, headers)
+    }
+}
+
+/// Speaks plain HTTP/1.0 over a TCP socket. This can't reach the real site, which needs HTTPS,
+/// but is enough for a stand-in server on localhost.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlainHttp;
+
+impl PlainHttp {
+    fn send(
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: Option<&str>,
+    ) -> io::Result<Response> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, url.to_owned()))?;
+        let (host, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, "/"),
+        };
+
+        let mut request = format!("{} {} HTTP/1.0\r\nHost: {}\r\n", method, path, host);
+        for (name, value) in headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        if let Some(body) = body {
+            request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+            request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+        request.push_str("\r\n");
+        request.push_str(body.unwrap_or_default());
+
+        let mut stream = TcpStream::connect(host)?;
+        stream.write_all(request.as_bytes())?;
+        // HTTP/1.0 servers close the connection after the body, so there's no need to look at
+        // the length or encoding
+        let mut raw = String::new();
+        stream.read_to_string(&mut raw)?;
+
+        let (head, body) = raw
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| invalid_data("response has no end of headers"))?;
+        let status = head
+            .split(' ')
+            .nth(1)
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| invalid_data(head.lines().next().unwrap_or_default()))?;
+        Ok(Response {
+            status,
+            body: body.to_owned(),
+        })
+    }
+}
+
+impl HttpClient for PlainHttp {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        PlainHttp::send("GET", url, headers, None)
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response> {
+        let body: Vec<String> = form
+            .iter()
+            .map(|(name, value)| format!("{}={}", url_encode(name), url_encode(value)))
+            .collect();
+        PlainHttp::send("POST", url, headers, Some(&body.join("&")))
+    }
+}
+
+/// Percent-encodes everything but the characters that are never reserved.
+fn url_encode(value: &str) -> String {
+    let mut result = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::http::{url_encode, Curl, HttpClient, PlainHttp, Response};
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn plain_http_sends_headers_and_reads_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                request.push(line.trim_end().to_owned());
+            }
+            (&stream)
+                .write_all(b"HTTP/1.0 404 Not Found\r\nContent-Type: text/plain\r\n\r\nnope\n")
+                .unwrap();
+            request
+        });
+
+        let response = PlainHttp
+            .get(
+                &format!("http://{}/2023/day/1/input", address),
+                &[("Cookie", "session=abc")],
+            )
+            .unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 404,
+                body: String::from("nope\n"),
+            }
+        );
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2023/day/1/input HTTP/1.0");
+        assert!(request.contains(&String::from("Cookie: session=abc")));
+    }
+
+    #[test]
+    fn curl_keeps_headers_off_the_command_line() {
+        let command = Curl::command();
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args[args.len() - 2..], ["--header", "@-"]);
+        assert!(!args.iter().any(|a| a.to_string_lossy().contains("Cookie")));
+    }
+
+    #[test]
+    fn url_encode_escapes_reserved() {
+        assert_eq!(url_encode("abc-123_.~"), "abc-123_.~");
+        assert_eq!(url_encode("a b&c=é"), "a%20b%26c%3D%C3%A9");
+    }
+
+    #[test]
+    fn plain_http_refuses_https() {
+        assert!(PlainHttp.get("https://adventofcode.com/", &[]).is_err());
+    }
+}
@@ -0,0 +1,291 @@
+pub mod http;
+
+use crate::fetch::http::{Curl, HttpClient, Response};
+use crate::workspace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{create_dir_all, read_to_string, rename, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// The least time left between two requests to the site.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(3);
+const USER_AGENT: &str = "aoc-2023 input fetcher (aoc-core)";
+
+/// Downloads puzzle inputs for a year and caches them as `YYYY/aoc-NN/input.txt` under a
+/// directory, which is laid out like the workspace root. A day with a non-empty cached input is
+/// never downloaded again.
+pub struct Fetcher<C: HttpClient = Curl> {
+    client: C,
+    base_url: String,
+    year: u16,
+    session: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    /// Fetches from the real site with the given session cookie.
+    pub fn new<P: AsRef<Path>>(year: u16, session: &str, cache_dir: P) -> Self {
+        Fetcher::with_client(Curl, BASE_URL, year, session, cache_dir)
+    }
+}
+
+impl<C> Fetcher<C>
+where
+    C: HttpClient,
+{
+    pub fn with_client<P: AsRef<Path>>(
+        client: C,
+        base_url: &str,
+        year: u16,
+        session: &str,
+        cache_dir: P,
+    ) -> Self {
+        Fetcher {
+            client,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            year,
+            session: session.trim().to_owned(),
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+            min_interval: MIN_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    pub fn with_min_interval(self, min_interval: Duration) -> Self {
+        Fetcher {
+            min_interval,
+            ..self
+        }
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        workspace::day_dir(&self.cache_dir, self.year, day).join("input.txt")
+    }
+
+    /// Whether `day` already has an input on disk. Empty placeholder files don't count.
+    pub fn is_cached(&self, day: u8) -> bool {
+        read_to_string(self.cache_path(day)).is_ok_and(|s| !s.is_empty())
+    }
+
+    /// Returns the input for `day`, from the cache if possible, otherwise downloading and caching
+    /// it. Failed downloads are never cached.
+    pub fn input(&mut self, day: u8) -> Result<String, FetchError> {
+        if !(1..=25).contains(&day) {
+            return Err(FetchError::InvalidDay(day));
+        }
+        let path = self.cache_path(day);
+        if let Ok(cached) = read_to_string(&path) {
+            if !cached.is_empty() {
+                return Ok(cached);
+            }
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, self.year, day);
+        let cookie = format!("session={}", self.session);
+        self.throttle();
+        let response = self
+            .client
+            .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])
+            .map_err(FetchError::Http)?;
+        if response.status != 200 {
+            return Err(FetchError::Status(response));
+        }
+
+        Self::store(&path, &response.body).map_err(|e| FetchError::Cache(path, e))?;
+        Ok(response.body)
+    }
+
+    /// Waits until at least `min_interval` has passed since the previous request.
+    fn throttle(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    /// Writes through a temporary file, so that an interrupted write never looks cached.
+    fn store(path: &Path, contents: &str) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        let partial = path.with_extension("txt.partial");
+        write(&partial, contents)?;
+        rename(&partial, path)
+    }
+}
+
+/// Reads the session cookie from the `AOC_SESSION` environment variable, or failing that from a
+/// `.session` file in `root`.
+pub fn find_session<P: AsRef<Path>>(root: P) -> Option<String> {
+    std::env::var("AOC_SESSION")
+        .ok()
+        .or_else(|| read_to_string(root.as_ref().join(".session")).ok())
+        .map(|s| s.trim().to_owned())
+        .filter(|s| !s.is_empty())
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Inputs only exist for days 1 to 25.
+    InvalidDay(u8),
+    /// The request couldn't be sent, or the response couldn't be read.
+    Http(io::Error),
+    /// The site answered with something other than `200 OK`, e.g. for a day that hasn't unlocked
+    /// yet or an expired session.
+    Status(Response),
+    /// The input was downloaded but couldn't be written to the cache.
+    Cache(PathBuf, io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::InvalidDay(day) => {
+                f.write_fmt(format_args!("There is no day {} to fetch", day))
+            }
+            FetchError::Http(e) => f.write_fmt(format_args!("Request failed: {}", e)),
+            FetchError::Status(response) => f.write_fmt(format_args!(
+                "Site answered {}: {}",
+                response.status,
+                response.body.trim()
+            )),
+            FetchError::Cache(path, e) => {
+                f.write_fmt(format_args!("Couldn't cache {}: {}", path.display(), e))
+            }
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::http::PlainHttp;
+    use crate::fetch::{FetchError, Fetcher};
+    use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::mpsc::{channel, Receiver};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    /// Starts a stand-in for the site that answers each request with the next of `responses`,
+    /// and passes on the request line and cookie of each request it gets.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut cookie = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.strip_prefix("Cookie: ") {
+                        cookie = value.trim_end().to_owned();
+                    }
+                }
+                let response = format!("HTTP/1.0 {} Whatever\r\n\r\n{}", status, body);
+                (&stream).write_all(response.as_bytes()).unwrap();
+                sender
+                    .send((request_line.trim_end().to_owned(), cookie))
+                    .unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", std::process::id(), name));
+        let _ = remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let (base_url, requests) = serve(vec![(200, "1 2 3\n")]);
+        let dir = cache_dir("once");
+        let mut fetcher = Fetcher::with_client(PlainHttp, &base_url, 2023, "abc\n", &dir);
+
+        assert!(!fetcher.is_cached(5));
+        assert_eq!(fetcher.input(5).unwrap(), "1 2 3\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            (
+                String::from("GET /2023/day/5/input HTTP/1.0"),
+                String::from("session=abc")
+            )
+        );
+        assert_eq!(
+            read_to_string(dir.join("2023/aoc-05/input.txt")).unwrap(),
+            "1 2 3\n"
+        );
+
+        // The stand-in has no more responses, so this would fail if it went to the network
+        assert!(fetcher.is_cached(5));
+        assert_eq!(fetcher.input(5).unwrap(), "1 2 3\n");
+        assert!(requests.try_recv().is_err());
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn replaces_empty_placeholders() {
+        let (base_url, _requests) = serve(vec![(200, "real\n")]);
+        let dir = cache_dir("placeholder");
+        create_dir_all(dir.join("2023/aoc-11")).unwrap();
+        write(dir.join("2023/aoc-11/input.txt"), "").unwrap();
+        let mut fetcher = Fetcher::with_client(PlainHttp, &base_url, 2023, "abc", &dir);
+
+        assert!(!fetcher.is_cached(11));
+        assert_eq!(fetcher.input(11).unwrap(), "real\n");
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn does_not_cache_failures() {
+        let (base_url, _requests) = serve(vec![(404, "Not unlocked yet\n"), (200, "ok\n")]);
+        let dir = cache_dir("failure");
+        let mut fetcher = Fetcher::with_client(PlainHttp, &base_url, 2023, "abc", &dir)
+            .with_min_interval(Duration::ZERO);
+
+        match fetcher.input(25) {
+            Err(FetchError::Status(response)) => assert_eq!(response.status, 404),
+            other => panic!("Expected a status error, got {:?}", other),
+        }
+        assert!(!fetcher.is_cached(25));
+        assert_eq!(fetcher.input(25).unwrap(), "ok\n");
+        assert!(matches!(fetcher.input(26), Err(FetchError::InvalidDay(26))));
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn throttles_requests() {
+        let (base_url, _requests) = serve(vec![(200, "a"), (200, "b")]);
+        let dir = cache_dir("throttle");
+        let mut fetcher = Fetcher::with_client(PlainHttp, &base_url, 2023, "abc", &dir)
+            .with_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        fetcher.input(1).unwrap();
+        fetcher.input(2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        remove_dir_all(&dir).unwrap();
+    }
+}
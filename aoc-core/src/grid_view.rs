@@ -0,0 +1,435 @@
+use crate::point::Point;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write as _};
+use std::io;
+use std::io::Write;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// Anything laid out as rows of cells, which can be drawn with a [GridView].
+pub trait Grid {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    /// The cell at `point`, which is always within the width and height.
+    fn cell(&self, point: Point<usize>) -> Cell;
+}
+
+impl Grid for Vec<Vec<char>> {
+    fn width(&self) -> usize {
+        self.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    fn height(&self) -> usize {
+        self.len()
+    }
+
+    /// Rows shorter than the widest one are padded with spaces.
+    fn cell(&self, point: Point<usize>) -> Cell {
+        Cell::plain(self[point.y].get(point.x).copied().unwrap_or(' '))
+    }
+}
+
+/// The ANSI terminal colours.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    /// One of the 256 colours of the extended palette.
+    Fixed(u8),
+}
+
+impl Colour {
+    /// The SGR parameters selecting this colour, counting from `base` (30 for the foreground, 40
+    /// for the background).
+    fn to_sgr(self, base: u8) -> String {
+        let offset = match self {
+            Colour::Black => 0,
+            Colour::Red => 1,
+            Colour::Green => 2,
+            Colour::Yellow => 3,
+            Colour::Blue => 4,
+            Colour::Magenta => 5,
+            Colour::Cyan => 6,
+            Colour::White => 7,
+            Colour::Fixed(n) => return format!("{};5;{}", base + 8, n),
+        };
+        (base + offset).to_string()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Style {
+    pub foreground: Option<Colour>,
+    pub background: Option<Colour>,
+    pub bold: bool,
+    pub dim: bool,
+}
+
+impl Style {
+    pub fn fg(colour: Colour) -> Self {
+        Style {
+            foreground: Some(colour),
+            ..Style::default()
+        }
+    }
+
+    pub fn bg(colour: Colour) -> Self {
+        Style {
+            background: Some(colour),
+            ..Style::default()
+        }
+    }
+
+    pub fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    pub fn dim(self) -> Self {
+        Style { dim: true, ..self }
+    }
+
+    /// Lays `other` over `self`, keeping whatever `other` leaves unset.
+    pub fn over(self, other: Style) -> Self {
+        Style {
+            foreground: other.foreground.or(self.foreground),
+            background: other.background.or(self.background),
+            bold: self.bold || other.bold,
+            dim: self.dim || other.dim,
+        }
+    }
+
+    /// The escape sequence switching to this style, which is empty for the default style.
+    fn to_ansi(self) -> String {
+        let mut params = vec![];
+        if self.bold {
+            params.push(String::from("1"));
+        }
+        if self.dim {
+            params.push(String::from("2"));
+        }
+        params.extend(self.foreground.map(|c| c.to_sgr(30)));
+        params.extend(self.background.map(|c| c.to_sgr(40)));
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", params.join(";"))
+        }
+    }
+}
+
+/// A character to draw, and how to draw it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn new(glyph: char, style: Style) -> Self {
+        Cell { glyph, style }
+    }
+
+    pub fn plain(glyph: char) -> Self {
+        Cell::new(glyph, Style::default())
+    }
+}
+
+/// The part of a grid to draw, as a rectangle of `width` by `height` cells from its top left
+/// corner at `x`, `y`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Viewport {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    /// A viewport of the given size with `point` in the middle. It may reach past the edges of
+    /// the grid, which is cropped when drawing.
+    pub fn centred_on(point: Point<usize>, width: usize, height: usize) -> Self {
+        Viewport {
+            x: point.x.saturating_sub(width / 2),
+            y: point.y.saturating_sub(height / 2),
+            width,
+            height,
+        }
+    }
+
+    /// Shrinks the viewport to fit within a grid of the given size.
+    fn crop(&self, width: usize, height: usize) -> Self {
+        let x = self.x.min(width);
+        let y = self.y.min(height);
+        Viewport {
+            x,
+            y,
+            width: self.width.min(width - x),
+            height: self.height.min(height - y),
+        }
+    }
+}
+
+/// Draws a [Grid], optionally cropped to a [Viewport], with highlights and markers laid over its
+/// own cells.
+///
+/// Colours are written as ANSI escape sequences unless turned off with [GridView::colour], in
+/// which case only the glyphs are drawn.
+pub struct GridView<'a, G: ?Sized> {
+    grid: &'a G,
+    viewport: Option<Viewport>,
+    /// Restyles cells, keeping their glyphs.
+    highlights: HashMap<Point<usize>, Style>,
+    /// Replaces cells outright.
+    markers: HashMap<Point<usize>, Cell>,
+    colour: bool,
+}
+
+impl<'a, G> GridView<'a, G>
+where
+    G: Grid + ?Sized,
+{
+    pub fn new(grid: &'a G) -> Self {
+        GridView {
+            grid,
+            viewport: None,
+            highlights: HashMap::new(),
+            markers: HashMap::new(),
+            colour: true,
+        }
+    }
+
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    pub fn colour(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+
+    /// Lays `style` over the cells at `points`, e.g. to pick out a path.
+    pub fn highlight<I>(mut self, points: I, style: Style) -> Self
+    where
+        I: IntoIterator<Item = Point<usize>>,
+    {
+        for point in points {
+            let existing = self.highlights.entry(point).or_default();
+            *existing = existing.over(style);
+        }
+        self
+    }
+
+    /// Draws `glyph` in `style` at `point` instead of the grid's own cell. Later markers replace
+    /// earlier ones.
+    pub fn mark(mut self, point: Point<usize>, glyph: char, style: Style) -> Self {
+        self.markers.insert(point, Cell::new(glyph, style));
+        self
+    }
+
+    /// The cell to draw at `point`, after markers and highlights.
+    pub fn cell(&self, point: Point<usize>) -> Cell {
+        let cell = self
+            .markers
+            .get(&point)
+            .copied()
+            .unwrap_or_else(|| self.grid.cell(point));
+        match self.highlights.get(&point) {
+            Some(&style) => Cell::new(cell.glyph, cell.style.over(style)),
+            None => cell,
+        }
+    }
+
+    fn visible(&self) -> Viewport {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        self.viewport
+            .unwrap_or(Viewport {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            })
+            .crop(width, height)
+    }
+}
+
+impl<G> Display for GridView<'_, G>
+where
+    G: Grid + ?Sized,
+{
+    /// Writes one line per row, each ending in a newline. Styles are reset at the end of each
+    /// line, so that nothing bleeds into whatever is printed next.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let visible = self.visible();
+        for y in visible.y..visible.y + visible.height {
+            let mut current = Style::default();
+            for x in visible.x..visible.x + visible.width {
+                let cell = self.cell(Point::new(x, y));
+                if self.colour && cell.style != current {
+                    if current != Style::default() {
+                        f.write_str("\x1b[0m")?;
+                    }
+                    f.write_str(&cell.style.to_ansi())?;
+                    current = cell.style;
+                }
+                f.write_char(cell.glyph)?;
+            }
+            if current != Style::default() {
+                f.write_str("\x1b[0m")?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+/// Redraws frames in place on a terminal, e.g. to watch a simulation step by step. Each frame is
+/// drawn over the previous one, and frames are held for at least the frame delay.
+pub struct Animation<W: Write> {
+    out: W,
+    frame_delay: Duration,
+    /// Lines taken up by the previous frame, which the next one is drawn over.
+    previous_lines: usize,
+    previous_at: Option<Instant>,
+}
+
+impl Animation<io::Stdout> {
+    /// Animates on standard output at `fps` frames per second.
+    pub fn stdout(fps: u32) -> Self {
+        Animation::new(io::stdout(), Duration::from_secs(1) / fps.max(1))
+    }
+}
+
+impl<W> Animation<W>
+where
+    W: Write,
+{
+    pub fn new(out: W, frame_delay: Duration) -> Self {
+        Animation {
+            out,
+            frame_delay,
+            previous_lines: 0,
+            previous_at: None,
+        }
+    }
+
+    /// Draws `frame` over the previous one, waiting first if the previous one was drawn less than
+    /// the frame delay ago.
+    pub fn frame<D: Display + ?Sized>(&mut self, frame: &D) -> io::Result<()> {
+        if let Some(previous_at) = self.previous_at {
+            let elapsed = previous_at.elapsed();
+            if elapsed < self.frame_delay {
+                sleep(self.frame_delay - elapsed);
+            }
+        }
+
+        let mut text = frame.to_string();
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        if self.previous_lines > 0 {
+            // Move back up to the start of the previous frame, and clear everything below
+            write!(self.out, "\x1b[{}F\x1b[J", self.previous_lines)?;
+        }
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()?;
+        self.previous_lines = text.lines().count();
+        self.previous_at = Some(Instant::now());
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid_view::{Animation, Cell, Colour, Grid, GridView, Style, Viewport};
+    use crate::point::Point;
+    use std::time::Duration;
+
+    fn grid(s: &str) -> Vec<Vec<char>> {
+        s.lines().map(|line| line.chars().collect()).collect()
+    }
+
+    #[test]
+    fn draws_plain_grids() {
+        let grid = grid("#..\n.#\n..#");
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.cell(Point::new(2, 1)), Cell::plain(' '));
+        let view = GridView::new(&grid).colour(false);
+        assert_eq!(view.to_string(), "#..\n.# \n..#\n");
+    }
+
+    #[test]
+    fn crops_to_viewport() {
+        let grid = grid("abcd\nefgh\nijkl\nmnop");
+        let view = GridView::new(&grid).colour(false);
+        let inner = Viewport {
+            x: 1,
+            y: 1,
+            width: 2,
+            height: 2,
+        };
+        assert_eq!(view.viewport(inner).to_string(), "fg\njk\n");
+
+        let view = GridView::new(&grid).colour(false);
+        let corner = Viewport::centred_on(Point::new(3, 3), 3, 3);
+        assert_eq!(view.viewport(corner).to_string(), "kl\nop\n");
+
+        let view = GridView::new(&grid).colour(false);
+        let outside = Viewport {
+            x: 9,
+            y: 0,
+            width: 2,
+            height: 2,
+        };
+        assert_eq!(view.viewport(outside).to_string(), "\n\n");
+    }
+
+    #[test]
+    fn styles_runs_of_cells() {
+        let grid = grid("abc\ndef");
+        let red = Style::fg(Colour::Red);
+        let view = GridView::new(&grid)
+            .highlight([Point::new(0, 0), Point::new(1, 0)], red)
+            .highlight([Point::new(1, 0)], Style::bg(Colour::Fixed(236)).bold())
+            .mark(Point::new(2, 1), 'X', Style::fg(Colour::Green).dim());
+        assert_eq!(
+            view.cell(Point::new(1, 0)).style,
+            Style {
+                foreground: Some(Colour::Red),
+                background: Some(Colour::Fixed(236)),
+                bold: true,
+                dim: false,
+            }
+        );
+        assert_eq!(
+            view.to_string(),
+            "\x1b[31ma\x1b[0m\x1b[1;31;48;5;236mb\x1b[0mc\n\
+             de\x1b[2;32mX\x1b[0m\n"
+        );
+        assert_eq!(view.colour(false).to_string(), "abc\ndeX\n");
+    }
+
+    #[test]
+    fn animates_in_place() {
+        let mut animation = Animation::new(Vec::new(), Duration::ZERO);
+        animation.frame("ab\ncd\n").unwrap();
+        animation
+            .frame(&GridView::new(&grid("ef\ngh")).colour(false))
+            .unwrap();
+        animation.frame("done").unwrap();
+        let out = String::from_utf8(animation.into_inner()).unwrap();
+        assert_eq!(out, "ab\ncd\n\x1b[2F\x1b[Jef\ngh\n\x1b[2F\x1b[Jdone\n");
+    }
+}
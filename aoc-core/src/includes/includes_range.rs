@@ -1,5 +1,5 @@
 use crate::includes::Includes;
-use std::ops::Range;
+use std::ops::{Range, RangeInclusive};
 
 impl<T> Includes<Range<T>> for Range<T>
 where
@@ -10,6 +10,24 @@ where
     }
 }
 
+impl<T> Includes<RangeInclusive<T>> for Range<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &RangeInclusive<T>) -> bool {
+        other.is_empty() || (*other.start() >= self.start && *other.end() < self.end)
+    }
+}
+
+impl<T> Includes<T> for Range<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::includes::Includes;
@@ -25,4 +43,20 @@ mod tests {
         assert_eq!(r1.includes(&r3), false);
         assert_eq!(r1.includes(&r1), true);
     }
+
+    #[test]
+    fn includes_range_inclusive() {
+        let r1 = 10..20;
+        assert!(r1.includes(&(10..=19)));
+        assert!(!r1.includes(&(10..=20)));
+        assert!(!r1.includes(&(9..=15)));
+    }
+
+    #[test]
+    fn includes_value() {
+        let r1 = 10..20;
+        assert!(r1.includes(&10));
+        assert!(r1.includes(&19));
+        assert!(!r1.includes(&20));
+    }
 }
@@ -0,0 +1,55 @@
+use crate::includes::Includes;
+use std::ops::{Range, RangeFrom, RangeInclusive};
+
+impl<T> Includes<RangeFrom<T>> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &RangeFrom<T>) -> bool {
+        other.start >= self.start
+    }
+}
+
+impl<T> Includes<Range<T>> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &Range<T>) -> bool {
+        other.is_empty() || other.start >= self.start
+    }
+}
+
+impl<T> Includes<RangeInclusive<T>> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &RangeInclusive<T>) -> bool {
+        other.is_empty() || *other.start() >= self.start
+    }
+}
+
+impl<T> Includes<T> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::includes::Includes;
+
+    #[test]
+    fn includes_range_from() {
+        let r1 = 10..;
+        assert!(r1.includes(&(15..)));
+        assert!(!r1.includes(&(5..)));
+        assert!(r1.includes(&(10..20)));
+        assert!(!r1.includes(&(9..20)));
+        assert!(r1.includes(&(10..=20)));
+        assert!(r1.includes(&10));
+        assert!(!r1.includes(&9));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::includes::Includes;
-use std::ops::RangeInclusive;
+use crate::num::One;
+use std::ops::{Range, RangeInclusive, Sub};
 
 impl<T> Includes<RangeInclusive<T>> for RangeInclusive<T>
 where
@@ -10,6 +11,25 @@ where
     }
 }
 
+impl<T> Includes<Range<T>> for RangeInclusive<T>
+where
+    T: PartialOrd + Copy + One<T> + Sub<Output = T>,
+{
+    fn includes(&self, other: &Range<T>) -> bool {
+        // a non-empty half-open range always has an inclusive upper bound of `end - 1`
+        other.is_empty() || (other.start >= *self.start() && other.end - T::one() <= *self.end())
+    }
+}
+
+impl<T> Includes<T> for RangeInclusive<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::includes::Includes;
@@ -26,4 +46,21 @@ mod tests {
         assert_eq!(r3.includes(&r1), true);
         assert_eq!(r1.includes(&r1), true);
     }
+
+    #[test]
+    fn includes_range() {
+        let r1 = 10..=20;
+        assert!(r1.includes(&(10..21)));
+        assert!(!r1.includes(&(10..22)));
+        assert!(!r1.includes(&(9..15)));
+        assert!((0u8..=u8::MAX).includes(&(0..u8::MAX)));
+    }
+
+    #[test]
+    fn includes_value() {
+        let r1 = 10..=20;
+        assert!(r1.includes(&10));
+        assert!(r1.includes(&20));
+        assert!(!r1.includes(&21));
+    }
 }
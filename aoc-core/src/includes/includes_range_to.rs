@@ -0,0 +1,56 @@
+use crate::includes::Includes;
+use std::ops::{Range, RangeInclusive, RangeTo};
+
+impl<T> Includes<RangeTo<T>> for RangeTo<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &RangeTo<T>) -> bool {
+        other.end <= self.end
+    }
+}
+
+impl<T> Includes<Range<T>> for RangeTo<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &Range<T>) -> bool {
+        other.is_empty() || other.end <= self.end
+    }
+}
+
+impl<T> Includes<RangeInclusive<T>> for RangeTo<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &RangeInclusive<T>) -> bool {
+        other.is_empty() || *other.end() < self.end
+    }
+}
+
+impl<T> Includes<T> for RangeTo<T>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::includes::Includes;
+
+    #[test]
+    fn includes_range_to() {
+        let r1 = ..10;
+        assert!(r1.includes(&(..5)));
+        assert!(!r1.includes(&(..15)));
+        assert!(r1.includes(&(0..10)));
+        assert!(!r1.includes(&(0..11)));
+        assert!(r1.includes(&(0..=9)));
+        assert!(!r1.includes(&(0..=10)));
+        assert!(r1.includes(&9));
+        assert!(!r1.includes(&10));
+    }
+}
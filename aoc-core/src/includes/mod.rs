@@ -1,5 +1,7 @@
 pub mod includes_range;
+pub mod includes_range_from;
 pub mod includes_range_inclusive;
+pub mod includes_range_to;
 
 /// Describes a thing that can be thought to fully include (or contain) another thing.
 pub trait Includes<T> {
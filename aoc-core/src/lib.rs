@@ -1,12 +1,26 @@
 extern crate core;
 
+pub mod answers;
+pub mod bench;
 pub mod cardinal_direction;
+pub mod direction;
+pub mod fetch;
+pub mod grid_view;
 pub mod includes;
+pub mod map;
 pub mod num;
+pub mod ordinal_direction;
 pub mod overlaps;
 pub mod point;
+pub mod point3;
+pub mod point_n;
 pub mod puzzle_input;
+pub mod scaffold;
+pub mod scanner;
 pub mod set;
+pub mod solution;
+pub mod submit;
+pub mod workspace;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,36 @@
+pub mod range_map;
+
+/// Describes a value that moves a key somewhere else, such as a signed offset applied to an
+/// unsigned key. Implementations must be translations: applying one to the bounds of a range
+/// gives the bounds of the moved range.
+pub trait Shift<K> {
+    fn shift(&self, key: K) -> K;
+}
+
+macro_rules! impl_shift {
+    ( $( $k:ty => $v:ty ),* ) => {
+        $(
+            impl Shift<$k> for $v {
+                fn shift(&self, key: $k) -> $k {
+                    key.checked_add_signed(*self).expect("Shifted key is out of bounds!")
+                }
+            }
+        )*
+    };
+}
+
+impl_shift!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
+macro_rules! impl_shift_signed {
+    ( $( $k:ty ),* ) => {
+        $(
+            impl Shift<$k> for $k {
+                fn shift(&self, key: $k) -> $k {
+                    key + *self
+                }
+            }
+        )*
+    };
+}
+
+impl_shift_signed!(i8, i16, i32, i64, i128, isize);
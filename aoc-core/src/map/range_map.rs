@@ -0,0 +1,323 @@
+use crate::map::Shift;
+use crate::num::{Bounded, One, Zero};
+use crate::set::range_set::RangeSet;
+use std::collections::BTreeMap;
+use std::ops::{Add, Neg, Range, RangeInclusive, Sub};
+
+/// Associates non-overlapping ranges of keys with values. Each range is stored once, keyed by its
+/// start, so looking up the value for a single key takes `O(log n)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RangeMap<K, V> {
+    /// By range start, holding the inclusive range end and the value.
+    entries: BTreeMap<K, (K, V)>,
+}
+
+impl<K, V> RangeMap<K, V>
+where
+    K: Ord + Copy + One<K> + Add<Output = K> + Sub<Output = K>,
+{
+    pub fn new() -> Self {
+        RangeMap {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Associates every key in `range` with `value`. Any parts of existing ranges that overlap
+    /// `range` are replaced, splitting them if needed. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<K>, value: V)
+    where
+        V: Clone,
+    {
+        if range.is_empty() {
+            return;
+        }
+        let (start, end) = (*range.start(), *range.end());
+        for (existing_start, (existing_end, existing_value)) in self.remove_overlapping(start, end)
+        {
+            if existing_start < start {
+                self.entries
+                    .insert(existing_start, (start - K::one(), existing_value.clone()));
+            }
+            if existing_end > end {
+                self.entries
+                    .insert(end + K::one(), (existing_end, existing_value));
+            }
+        }
+        self.entries.insert(start, (end, value));
+    }
+
+    /// Like [RangeMap::insert], for a half-open range.
+    pub fn insert_range(&mut self, range: Range<K>, value: V)
+    where
+        V: Clone,
+    {
+        if range.start < range.end {
+            self.insert(range.start..=range.end - K::one(), value);
+        }
+    }
+
+    /// Returns the value associated with `key`, if any.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_entry(key).map(|(_, v)| v)
+    }
+
+    /// Returns the range containing `key` and its value, if any.
+    pub fn get_entry(&self, key: &K) -> Option<(RangeInclusive<K>, &V)> {
+        let (start, (end, value)) = self.entries.range(..=key).next_back()?;
+        if end >= key {
+            Some((*start..=*end, value))
+        } else {
+            None
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over each range and its value, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = (RangeInclusive<K>, &V)> + '_ {
+        self.entries
+            .iter()
+            .map(|(start, (end, value))| (*start..=*end, value))
+    }
+
+    /// Returns the set of keys that have a value.
+    pub fn keys(&self) -> RangeSet<K> {
+        self.iter().map(|(r, _)| r).collect()
+    }
+
+    /// Returns `key` moved by the value it's associated with, or `key` itself if it has none.
+    pub fn shift(&self, key: K) -> K
+    where
+        V: Shift<K>,
+    {
+        match self.get(&key) {
+            Some(v) => v.shift(key),
+            None => key,
+        }
+    }
+
+    /// Moves every key in `set` by the value it's associated with. Keys without a value are kept
+    /// as they are.
+    pub fn shift_set(&self, set: &RangeSet<K>) -> RangeSet<K>
+    where
+        V: Shift<K>,
+    {
+        let mut result = vec![];
+        for range in set.iter_inclusive() {
+            for (piece, value) in self.pieces(range) {
+                match value {
+                    Some(v) => result.push(v.shift(*piece.start())..=v.shift(*piece.end())),
+                    None => result.push(piece),
+                }
+            }
+        }
+        RangeSet::from_inclusive(result)
+    }
+
+    /// Splits `within` into consecutive pieces that each either share one value, or have none.
+    pub fn pieces(&self, within: RangeInclusive<K>) -> Vec<(RangeInclusive<K>, Option<&V>)> {
+        let mut result = vec![];
+        if within.is_empty() {
+            return result;
+        }
+        let (mut start, end) = (*within.start(), *within.end());
+        // start from the entry covering `start`, if there is one
+        let first = self
+            .entries
+            .range(..=start)
+            .next_back()
+            .map(|(s, _)| *s)
+            .unwrap_or(start);
+        for (entry_start, (entry_end, value)) in self.entries.range(first..=end) {
+            if *entry_end < start {
+                continue;
+            }
+            if *entry_start > start {
+                result.push((start..=*entry_start - K::one(), None));
+            }
+            let piece_end = *entry_end.min(&end);
+            let piece_start = *entry_start.max(&start);
+            result.push((piece_start..=piece_end, Some(value)));
+            if piece_end == end {
+                return result;
+            }
+            start = piece_end + K::one();
+        }
+        result.push((start..=end, None));
+        result
+    }
+
+    /// Returns the single map equivalent to shifting keys by `self`, then by `next`. Keys
+    /// without a value in either map are unmoved by it, and keys that end up unmoved overall are
+    /// left out of the result.
+    pub fn then(&self, next: &Self) -> Self
+    where
+        K: Bounded,
+        V: Shift<K> + Copy + Add<Output = V> + Neg<Output = V> + Zero<V> + PartialEq,
+    {
+        let mut result = RangeMap::new();
+        for (piece, value) in self.pieces(K::MIN..=K::MAX) {
+            let offset = value.copied().unwrap_or(V::zero());
+            let back = -offset;
+            let image = offset.shift(*piece.start())..=offset.shift(*piece.end());
+            for (next_piece, next_value) in next.pieces(image) {
+                let total = offset + next_value.copied().unwrap_or(V::zero());
+                if total != V::zero() {
+                    let source = back.shift(*next_piece.start())..=back.shift(*next_piece.end());
+                    result.insert(source, total);
+                }
+            }
+        }
+        result
+    }
+
+    /// Removes and returns every entry that overlaps `start..=end`.
+    fn remove_overlapping(&mut self, start: K, end: K) -> Vec<(K, (K, V))> {
+        let mut starts: Vec<K> = vec![];
+        if let Some((s, (e, _))) = self.entries.range(..start).next_back() {
+            if *e >= start {
+                starts.push(*s);
+            }
+        }
+        starts.extend(self.entries.range(start..=end).map(|(s, _)| *s));
+        starts
+            .into_iter()
+            .map(|s| (s, self.entries.remove(&s).unwrap()))
+            .collect()
+    }
+}
+
+impl<K, V> Default for RangeMap<K, V>
+where
+    K: Ord + Copy + One<K> + Add<Output = K> + Sub<Output = K>,
+{
+    fn default() -> Self {
+        RangeMap::new()
+    }
+}
+
+impl<K, V> FromIterator<(RangeInclusive<K>, V)> for RangeMap<K, V>
+where
+    K: Ord + Copy + One<K> + Add<Output = K> + Sub<Output = K>,
+    V: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (RangeInclusive<K>, V)>>(iter: I) -> Self {
+        let mut map = RangeMap::new();
+        for (range, value) in iter {
+            map.insert(range, value);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::map::range_map::RangeMap;
+    use crate::set::range_set::RangeSet;
+
+    #[test]
+    fn get() {
+        let map: RangeMap<u32, char> = [(10..=19, 'a'), (30..=39, 'b')].into_iter().collect();
+        assert_eq!(map.get(&9), None);
+        assert_eq!(map.get(&10), Some(&'a'));
+        assert_eq!(map.get(&19), Some(&'a'));
+        assert_eq!(map.get(&20), None);
+        assert_eq!(map.get_entry(&35), Some((30..=39, &'b')));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn insert_splits_existing_ranges() {
+        let mut map = RangeMap::new();
+        map.insert(0..=20, 'a');
+        map.insert(5..=9, 'b');
+        map.insert_range(15..25, 'c');
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![
+                (0..=4, &'a'),
+                (5..=9, &'b'),
+                (10..=14, &'a'),
+                (15..=24, &'c')
+            ]
+        );
+        map.insert(3..=16, 'd');
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(0..=2, &'a'), (3..=16, &'d'), (17..=24, &'c')]
+        );
+        assert_eq!(map.keys().len(), Some(25));
+        assert_eq!(map.keys().span(), Some(0..=24));
+    }
+
+    #[test]
+    fn insert_at_bounds() {
+        let mut map = RangeMap::new();
+        map.insert(0u8..=u8::MAX, 1);
+        map.insert(u8::MAX..=u8::MAX, 2);
+        map.insert(0..=0, 3);
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(0..=0, &3), (1..=254, &1), (255..=255, &2)]
+        );
+    }
+
+    #[test]
+    fn shift() {
+        let map: RangeMap<u64, i64> = [(98..=99, -48i64), (50..=97, 2)].into_iter().collect();
+        assert_eq!(map.shift(79), 81);
+        assert_eq!(map.shift(98), 50);
+        assert_eq!(map.shift(14), 14);
+    }
+
+    #[test]
+    fn shift_set() {
+        let map: RangeMap<u64, i64> = [(98..=99, -48i64), (50..=97, 2)].into_iter().collect();
+        let set = RangeSet::new(vec![40..60, 95..105]);
+        let shifted = map.shift_set(&set);
+        assert_eq!(shifted.iter().collect::<Vec<_>>(), vec![40..62, 97..105]);
+        assert_eq!(shifted.len(), set.len());
+    }
+
+    #[test]
+    fn pieces() {
+        let map: RangeMap<u32, char> = [(10..=19, 'a'), (20..=24, 'b')].into_iter().collect();
+        assert_eq!(
+            map.pieces(5..=30),
+            vec![
+                (5..=9, None),
+                (10..=19, Some(&'a')),
+                (20..=24, Some(&'b')),
+                (25..=30, None)
+            ]
+        );
+        assert_eq!(map.pieces(12..=14), vec![(12..=14, Some(&'a'))]);
+        assert_eq!(map.pieces(0..=u32::MAX).len(), 4);
+    }
+
+    #[test]
+    fn then() {
+        let first: RangeMap<u64, i64> = [(98..=99, -48i64), (50..=97, 2)].into_iter().collect();
+        let second: RangeMap<u64, i64> = [(15..=51, -15i64), (52..=53, -15), (0..=14, 39)]
+            .into_iter()
+            .collect();
+        let composed = first.then(&second);
+        for key in 0..120u64 {
+            assert_eq!(composed.shift(key), second.shift(first.shift(key)));
+        }
+        let set = RangeSet::from(0..120);
+        assert_eq!(
+            composed.shift_set(&set).values().collect::<Vec<_>>(),
+            second
+                .shift_set(&first.shift_set(&set))
+                .values()
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -47,6 +47,30 @@ macro_rules! impl_bounded {
 
 impl_bounded!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
 
+/// Arithmetic that returns `None` instead of overflowing.
+pub trait Checked: Sized {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked {
+    ( $( $x:ty ),* ) => {
+        $(
+            impl Checked for $x {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$x>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$x>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
 pub trait GreatestCommonFactor<T> {
     fn gcf(&self, other: &T) -> T;
 }
@@ -0,0 +1,76 @@
+use crate::direction::Heading;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum OrdinalDirection {
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl OrdinalDirection {
+    /// All four ordinal directions, clockwise from north-east.
+    pub const ALL: [OrdinalDirection; 4] = [
+        OrdinalDirection::NorthEast,
+        OrdinalDirection::SouthEast,
+        OrdinalDirection::SouthWest,
+        OrdinalDirection::NorthWest,
+    ];
+
+    /// Returns the direction 90° counter-clockwise from `self`.
+    pub fn turn_left(&self) -> Self {
+        match self {
+            OrdinalDirection::NorthEast => OrdinalDirection::NorthWest,
+            OrdinalDirection::NorthWest => OrdinalDirection::SouthWest,
+            OrdinalDirection::SouthWest => OrdinalDirection::SouthEast,
+            OrdinalDirection::SouthEast => OrdinalDirection::NorthEast,
+        }
+    }
+
+    /// Returns the direction 90° clockwise from `self`.
+    pub fn turn_right(&self) -> Self {
+        match self {
+            OrdinalDirection::NorthEast => OrdinalDirection::SouthEast,
+            OrdinalDirection::SouthEast => OrdinalDirection::SouthWest,
+            OrdinalDirection::SouthWest => OrdinalDirection::NorthWest,
+            OrdinalDirection::NorthWest => OrdinalDirection::NorthEast,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            OrdinalDirection::NorthEast => OrdinalDirection::SouthWest,
+            OrdinalDirection::SouthWest => OrdinalDirection::NorthEast,
+            OrdinalDirection::NorthWest => OrdinalDirection::SouthEast,
+            OrdinalDirection::SouthEast => OrdinalDirection::NorthWest,
+        }
+    }
+}
+
+impl Heading for OrdinalDirection {
+    fn to_delta(&self) -> (i8, i8) {
+        match self {
+            OrdinalDirection::NorthEast => (1, -1),
+            OrdinalDirection::NorthWest => (-1, -1),
+            OrdinalDirection::SouthEast => (1, 1),
+            OrdinalDirection::SouthWest => (-1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ordinal_direction::OrdinalDirection;
+
+    #[test]
+    fn turning() {
+        for dir in OrdinalDirection::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+        }
+        assert_eq!(
+            OrdinalDirection::NorthEast.turn_right(),
+            OrdinalDirection::SouthEast
+        );
+    }
+}
@@ -1,8 +1,18 @@
 pub mod overlaps_range;
+pub mod overlaps_range_from;
 pub mod overlaps_range_inclusive;
+pub mod overlaps_range_to;
 
 /// Describes a thing that can be thought to partially overlap with another thing.
 pub trait Overlaps<T> {
     /// Returns whether or not `self` at least partially overlaps with `other`.
     fn overlaps(&self, other: &T) -> bool;
 }
+
+/// Companion to [Overlaps] for things that can also describe where they overlap.
+pub trait Intersect<T> {
+    type Output;
+
+    /// Returns the part of `self` that is also in `other`, or `None` if they don't overlap.
+    fn intersect(&self, other: &T) -> Option<Self::Output>;
+}
@@ -1,5 +1,6 @@
-use crate::overlaps::Overlaps;
-use std::ops::Range;
+use crate::num::One;
+use crate::overlaps::{Intersect, Overlaps};
+use std::ops::{Add, Range, RangeFrom, RangeInclusive, RangeTo};
 
 fn starts_in<T>(s: &Range<T>, o: &Range<T>) -> bool
 where
@@ -27,9 +28,94 @@ where
     }
 }
 
+impl<T> Overlaps<RangeInclusive<T>> for Range<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeInclusive<T>) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.start <= *other.end()
+            && *other.start() < self.end
+    }
+}
+
+impl<T> Overlaps<RangeFrom<T>> for Range<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeFrom<T>) -> bool {
+        !self.is_empty() && self.end > other.start
+    }
+}
+
+impl<T> Overlaps<RangeTo<T>> for Range<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeTo<T>) -> bool {
+        !self.is_empty() && self.start < other.end
+    }
+}
+
+impl<T> Overlaps<T> for Range<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
+impl<T> Intersect<Range<T>> for Range<T>
+where
+    T: PartialOrd + Copy,
+{
+    type Output = Range<T>;
+
+    fn intersect(&self, other: &Range<T>) -> Option<Self::Output> {
+        let start = if self.start > other.start {
+            self.start
+        } else {
+            other.start
+        };
+        let end = if self.end < other.end {
+            self.end
+        } else {
+            other.end
+        };
+        (start < end).then_some(start..end)
+    }
+}
+
+impl<T> Intersect<RangeInclusive<T>> for Range<T>
+where
+    T: PartialOrd + Copy + One<T> + Add<Output = T>,
+{
+    type Output = Range<T>;
+
+    fn intersect(&self, other: &RangeInclusive<T>) -> Option<Self::Output> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let start = if self.start > *other.start() {
+            self.start
+        } else {
+            *other.start()
+        };
+        // `other.end() < self.end` means `other.end() + 1` can't overflow
+        let end = if *other.end() < self.end {
+            *other.end() + T::one()
+        } else {
+            self.end
+        };
+        Some(start..end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::overlaps::Overlaps;
+    use crate::overlaps::{Intersect, Overlaps};
 
     #[test]
     fn does_detect_overlap() {
@@ -50,4 +136,33 @@ mod tests {
         assert_eq!((0..5).overlaps(&(2..3)), (2..3).overlaps(&(0..5)));
         assert_eq!((10..15).overlaps(&(15..20)), (15..20).overlaps(&(10..15)));
     }
+
+    #[test]
+    fn mixed_range_kinds() {
+        assert!((0..5).overlaps(&(4..=7)));
+        assert!(!(0..5).overlaps(&(5..=7)));
+        assert!((0..5).overlaps(&(4..)));
+        assert!(!(0..5).overlaps(&(5..)));
+        assert!((0..5).overlaps(&(..1)));
+        assert!(!(0..5).overlaps(&(..0)));
+        assert!((0..5).overlaps(&4));
+        assert!(!(0..5).overlaps(&5));
+    }
+
+    #[test]
+    fn empty_ranges_never_overlap() {
+        let empty = 3..3;
+        assert!(!empty.overlaps(&(0..=10)));
+        assert!(!empty.overlaps(&(0..)));
+        assert!(!empty.overlaps(&(..10)));
+    }
+
+    #[test]
+    fn intersect() {
+        assert_eq!((0..5).intersect(&(3..8)), Some(3..5));
+        assert_eq!((0..5).intersect(&(5..8)), None);
+        assert_eq!((0..5).intersect(&(1..=2)), Some(1..3));
+        assert_eq!((0..5).intersect(&(3..=9)), Some(3..5));
+        assert_eq!((0u8..255).intersect(&(3..=u8::MAX)), Some(3..255));
+    }
 }
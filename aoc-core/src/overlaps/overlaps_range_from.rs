@@ -0,0 +1,78 @@
+use crate::overlaps::{Intersect, Overlaps};
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo};
+
+impl<T> Overlaps<RangeFrom<T>> for RangeFrom<T> {
+    fn overlaps(&self, _other: &RangeFrom<T>) -> bool {
+        true
+    }
+}
+
+impl<T> Overlaps<RangeTo<T>> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeTo<T>) -> bool {
+        self.start < other.end
+    }
+}
+
+impl<T> Overlaps<Range<T>> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &Range<T>) -> bool {
+        other.overlaps(self)
+    }
+}
+
+impl<T> Overlaps<RangeInclusive<T>> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeInclusive<T>) -> bool {
+        other.overlaps(self)
+    }
+}
+
+impl<T> Overlaps<T> for RangeFrom<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
+impl<T> Intersect<Range<T>> for RangeFrom<T>
+where
+    T: PartialOrd + Copy,
+{
+    type Output = Range<T>;
+
+    fn intersect(&self, other: &Range<T>) -> Option<Self::Output> {
+        (self.start..other.end).intersect(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::overlaps::{Intersect, Overlaps};
+
+    #[test]
+    fn does_detect_overlap() {
+        assert!((5..).overlaps(&(100..)));
+        assert!((5..).overlaps(&(..6)));
+        assert!(!(5..).overlaps(&(..5)));
+        assert!((5..).overlaps(&(0..6)));
+        assert!(!(5..).overlaps(&(0..5)));
+        assert!((5..).overlaps(&(0..=5)));
+        assert!((5..).overlaps(&5));
+        assert!(!(5..).overlaps(&4));
+    }
+
+    #[test]
+    fn intersect() {
+        assert_eq!((5..).intersect(&(0..10)), Some(5..10));
+        assert_eq!((5..).intersect(&(0..5)), None);
+    }
+}
@@ -1,5 +1,6 @@
-use crate::overlaps::Overlaps;
-use std::ops::RangeInclusive;
+use crate::num::One;
+use crate::overlaps::{Intersect, Overlaps};
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, Sub};
 
 fn starts_in<T>(s: &RangeInclusive<T>, o: &RangeInclusive<T>) -> bool
 where
@@ -27,9 +28,91 @@ where
     }
 }
 
+impl<T> Overlaps<Range<T>> for RangeInclusive<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &Range<T>) -> bool {
+        other.overlaps(self)
+    }
+}
+
+impl<T> Overlaps<RangeFrom<T>> for RangeInclusive<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeFrom<T>) -> bool {
+        !self.is_empty() && *self.end() >= other.start
+    }
+}
+
+impl<T> Overlaps<RangeTo<T>> for RangeInclusive<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeTo<T>) -> bool {
+        !self.is_empty() && *self.start() < other.end
+    }
+}
+
+impl<T> Overlaps<T> for RangeInclusive<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
+impl<T> Intersect<RangeInclusive<T>> for RangeInclusive<T>
+where
+    T: PartialOrd + Copy,
+{
+    type Output = RangeInclusive<T>;
+
+    fn intersect(&self, other: &RangeInclusive<T>) -> Option<Self::Output> {
+        let start = if self.start() > other.start() {
+            *self.start()
+        } else {
+            *other.start()
+        };
+        let end = if self.end() < other.end() {
+            *self.end()
+        } else {
+            *other.end()
+        };
+        (start <= end).then_some(start..=end)
+    }
+}
+
+impl<T> Intersect<Range<T>> for RangeInclusive<T>
+where
+    T: PartialOrd + Copy + One<T> + Sub<Output = T>,
+{
+    type Output = RangeInclusive<T>;
+
+    fn intersect(&self, other: &Range<T>) -> Option<Self::Output> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let start = if *self.start() > other.start {
+            *self.start()
+        } else {
+            other.start
+        };
+        // `other` is non-empty, so `other.end - 1` can't underflow
+        let end = if *self.end() < other.end {
+            *self.end()
+        } else {
+            other.end - T::one()
+        };
+        Some(start..=end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::overlaps::Overlaps;
+    use crate::overlaps::{Intersect, Overlaps};
 
     #[test]
     fn does_detect_overlap() {
@@ -53,4 +136,26 @@ mod tests {
             (15..=20).overlaps(&(10..=15))
         );
     }
+
+    #[test]
+    fn mixed_range_kinds() {
+        assert!((0..=5).overlaps(&(5..7)));
+        assert!(!(0..=5).overlaps(&(6..7)));
+        assert!((0..=5).overlaps(&(5..)));
+        assert!(!(0..=5).overlaps(&(6..)));
+        assert!((0..=5).overlaps(&(..1)));
+        assert!(!(0..=5).overlaps(&(..0)));
+        assert!((0..=5).overlaps(&5));
+        assert!(!(0..=5).overlaps(&6));
+    }
+
+    #[test]
+    fn intersect() {
+        assert_eq!((0..=5).intersect(&(3..=8)), Some(3..=5));
+        assert_eq!((0..=5).intersect(&(5..=8)), Some(5..=5));
+        assert_eq!((0..=5).intersect(&(6..=8)), None);
+        assert_eq!((0..=5).intersect(&(2..4)), Some(2..=3));
+        assert_eq!((0..=5).intersect(&(4..9)), Some(4..=5));
+        assert_eq!((0..=5).intersect(&(6..9)), None);
+    }
 }
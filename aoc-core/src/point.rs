@@ -1,6 +1,9 @@
-use crate::cardinal_direction::CardinalDirection;
-use crate::num::{Bounded, One};
-use std::ops::{Add, Sub};
+use crate::direction::Heading;
+use crate::num::{Bounded, One, Zero};
+use crate::point_n::abs_diff;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Point<T> {
@@ -18,13 +21,11 @@ impl<T> Point<T>
 where
     T: Add<Output = T> + Sub<Output = T> + One<T> + Copy,
 {
-    pub fn go(&self, dir: &CardinalDirection) -> Self {
-        match dir {
-            CardinalDirection::North => Point::new(self.x, self.y - T::one()),
-            CardinalDirection::South => Point::new(self.x, self.y + T::one()),
-            CardinalDirection::East => Point::new(self.x + T::one(), self.y),
-            CardinalDirection::West => Point::new(self.x - T::one(), self.y),
-        }
+    /// Returns the point one step away from `self` towards `dir`, which may be any of the
+    /// direction types.
+    pub fn go<D: Heading>(&self, dir: &D) -> Self {
+        let (dx, dy) = dir.to_delta();
+        Point::new(step(self.x, dx), step(self.y, dy))
     }
 }
 
@@ -32,43 +33,186 @@ impl<T> Point<T>
 where
     T: Add<Output = T> + Sub<Output = T> + One<T> + Copy + Bounded + PartialOrd,
 {
-    pub fn checked_go(&self, dir: &CardinalDirection) -> Option<Self> {
-        match dir {
-            CardinalDirection::North => {
-                if self.y > T::MIN {
-                    Some(self.go(dir))
-                } else {
-                    None
-                }
-            }
-            CardinalDirection::South => {
-                if self.y < T::MAX {
-                    Some(self.go(dir))
-                } else {
-                    None
-                }
-            }
-            CardinalDirection::East => {
-                if self.x < T::MAX {
-                    Some(self.go(dir))
-                } else {
-                    None
-                }
-            }
-            CardinalDirection::West => {
-                if self.x > T::MIN {
-                    Some(self.go(dir))
-                } else {
-                    None
-                }
-            }
+    /// Like [Point::go], but returns `None` instead of leaving the bounds of `T`.
+    pub fn checked_go<D: Heading>(&self, dir: &D) -> Option<Self> {
+        let (dx, dy) = dir.to_delta();
+        if can_step(self.x, dx) && can_step(self.y, dy) {
+            Some(self.go(dir))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + PartialOrd + Zero<T> + Copy,
+{
+    /// Returns the sum of the absolute differences of each coordinate.
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Returns the larger of the absolute differences of each coordinate.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+        if dx > dy {
+            dx
+        } else {
+            dy
         }
     }
 }
 
+impl<T> Point<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    /// Rotates `self` 90° clockwise about the origin. As north is towards smaller `y`, east
+    /// becomes south.
+    pub fn rotate_right(&self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotates `self` 90° counter-clockwise about the origin. As north is towards smaller `y`,
+    /// east becomes north.
+    pub fn rotate_left(&self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+}
+
+fn step<T>(value: T, delta: i8) -> T
+where
+    T: Add<Output = T> + Sub<Output = T> + One<T>,
+{
+    match delta.signum() {
+        -1 => value - T::one(),
+        1 => value + T::one(),
+        _ => value,
+    }
+}
+
+fn can_step<T>(value: T, delta: i8) -> bool
+where
+    T: Bounded + PartialOrd,
+{
+    match delta.signum() {
+        -1 => value > T::MIN,
+        1 => value < T::MAX,
+        _ => true,
+    }
+}
+
+impl<T> Add for Point<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T> AddAssign for Point<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T> Sub for Point<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T> SubAssign for Point<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> Mul<T> for Point<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T> Neg for Point<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T> PartialOrd for Point<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Points are ordered the way a grid is read: by row (`y`), then by column (`x`).
+impl<T> Ord for Point<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.y.cmp(&other.y).then_with(|| self.x.cmp(&other.x))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(value: Point<T>) -> Self {
+        (value.x, value.y)
+    }
+}
+
+impl<T> Display for Point<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{},{}", self.x, self.y))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cardinal_direction::CardinalDirection;
+    use crate::direction::Direction;
+    use crate::ordinal_direction::OrdinalDirection;
     use crate::point::Point;
 
     #[test]
@@ -97,4 +241,75 @@ mod tests {
         assert_eq!(max.checked_go(&CardinalDirection::South), None);
         assert_eq!(max.checked_go(&CardinalDirection::East), None);
     }
+
+    #[test]
+    fn go_diagonal() {
+        let p = Point::new(5i32, 5);
+        assert_eq!(p.go(&OrdinalDirection::NorthEast), Point::new(6, 4));
+        assert_eq!(p.go(&OrdinalDirection::SouthWest), Point::new(4, 6));
+        assert_eq!(p.go(&Direction::North), p.go(&CardinalDirection::North));
+        assert_eq!(p.go(&Direction::NorthWest), Point::new(4, 4));
+    }
+
+    #[test]
+    fn checked_go_diagonal() {
+        let min = Point::new(u8::MIN, 5);
+        assert_eq!(min.checked_go(&OrdinalDirection::NorthWest), None);
+        assert_eq!(
+            min.checked_go(&OrdinalDirection::NorthEast),
+            Some(Point::new(1u8, 4))
+        );
+        let neighbours = Direction::ALL
+            .iter()
+            .filter_map(|d| min.checked_go(d))
+            .count();
+        assert_eq!(neighbours, 5);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3i32, -4);
+        let b = Point::new(1, 2);
+        assert_eq!(a + b, Point::new(4, -2));
+        assert_eq!(a - b, Point::new(2, -6));
+        assert_eq!(a * 2, Point::new(6, -8));
+        assert_eq!(-a, Point::new(-3, 4));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(2u32, 9);
+        let b = Point::new(7, 6);
+        assert_eq!(a.manhattan_distance(&b), 8);
+        assert_eq!(b.manhattan_distance(&a), 8);
+        assert_eq!(a.chebyshev_distance(&b), 5);
+        assert_eq!(a.manhattan_distance(&a), 0);
+    }
+
+    #[test]
+    fn rotation() {
+        let east = Point::new(1i32, 0);
+        assert_eq!(east.rotate_right(), Point::new(0, 1));
+        assert_eq!(east.rotate_left(), Point::new(0, -1));
+        let p = Point::new(3i32, 5);
+        assert_eq!(p.rotate_right().rotate_left(), p);
+        assert_eq!(p.rotate_right().rotate_right(), -p);
+    }
+
+    #[test]
+    fn ordering_and_conversion() {
+        let mut points: Vec<Point<u8>> = vec![(2, 1).into(), (0, 2).into(), (5, 0).into()];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![Point::new(5, 0), Point::new(2, 1), Point::new(0, 2)]
+        );
+        let (x, y) = points[0].into();
+        assert_eq!((x, y), (5, 0));
+        assert_eq!(points[1].to_string(), "2,1");
+    }
 }
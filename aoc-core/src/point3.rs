@@ -0,0 +1,197 @@
+use crate::num::{Bounded, One, Zero};
+use crate::point_n::{ParsePointError, PointN};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> Point3<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + PartialOrd + Zero<T> + Copy,
+{
+    /// Returns the sum of the absolute differences of each coordinate.
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        PointN::from(*self).manhattan_distance(&PointN::from(*other))
+    }
+
+    /// Returns the largest absolute difference of any coordinate.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        PointN::from(*self).chebyshev_distance(&PointN::from(*other))
+    }
+}
+
+impl<T> Point3<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + One<T> + Bounded + PartialEq + Copy,
+{
+    /// Returns the 6 points that share a face with `self`, or fewer at the bounds of `T`.
+    pub fn neighbours(&self) -> Vec<Self> {
+        PointN::from(*self)
+            .neighbours()
+            .into_iter()
+            .map(Point3::from)
+            .collect()
+    }
+
+    /// Returns the 26 points that share a face, edge or corner with `self`, or fewer at the bounds
+    /// of `T`.
+    pub fn all_neighbours(&self) -> Vec<Self> {
+        PointN::from(*self)
+            .all_neighbours()
+            .into_iter()
+            .map(Point3::from)
+            .collect()
+    }
+}
+
+impl<T> Add for Point3<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T> AddAssign for Point3<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T> Sub for Point3<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> SubAssign for Point3<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> Mul<T> for Point3<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T> Neg for Point3<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl<T> From<PointN<T, 3>> for Point3<T> {
+    fn from(value: PointN<T, 3>) -> Self {
+        let [x, y, z] = value.coords;
+        Point3::new(x, y, z)
+    }
+}
+
+impl<T> From<Point3<T>> for PointN<T, 3> {
+    fn from(value: Point3<T>) -> Self {
+        PointN::new([value.x, value.y, value.z])
+    }
+}
+
+impl<T> Display for Point3<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{},{},{}", self.x, self.y, self.z))
+    }
+}
+
+/// Parses comma-separated coordinates such as `1,0,1` or `19, 13, 30`.
+impl<T> FromStr for Point3<T>
+where
+    T: FromStr,
+{
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<PointN<T, 3>>().map(Point3::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point3::Point3;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point3::new(1i64, -2, 3);
+        let b = Point3::new(10, 20, 30);
+        assert_eq!(a + b, Point3::new(11, 18, 33));
+        assert_eq!(b - a, Point3::new(9, 22, 27));
+        assert_eq!(a * 3, Point3::new(3, -6, 9));
+        assert_eq!(-a, Point3::new(-1, 2, -3));
+    }
+
+    #[test]
+    fn distances_and_neighbours() {
+        let a = Point3::new(1u8, 1, 1);
+        let b = Point3::new(4, 0, 2);
+        assert_eq!(a.manhattan_distance(&b), 5);
+        assert_eq!(a.chebyshev_distance(&b), 3);
+        assert_eq!(a.neighbours().len(), 6);
+        assert_eq!(a.all_neighbours().len(), 26);
+        assert!(a.neighbours().contains(&Point3::new(1, 1, 0)));
+    }
+
+    #[test]
+    fn parse() {
+        let p: Point3<i64> = "19, 13, 30".parse().unwrap();
+        assert_eq!(p, Point3::new(19, 13, 30));
+        assert_eq!(p.to_string(), "19,13,30");
+        assert!("1,0".parse::<Point3<i64>>().is_err());
+    }
+}
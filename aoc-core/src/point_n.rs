@@ -0,0 +1,337 @@
+use crate::num::{Bounded, One, Zero};
+use std::array::from_fn;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A point with `N` coordinates of type `T`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PointN<T, const N: usize> {
+    pub coords: [T; N],
+}
+
+impl<T, const N: usize> PointN<T, N> {
+    pub fn new(coords: [T; N]) -> Self {
+        PointN { coords }
+    }
+}
+
+impl<T, const N: usize> PointN<T, N>
+where
+    T: Sub<Output = T> + PartialOrd + Copy,
+{
+    /// Returns the sum of the absolute differences of each coordinate.
+    pub fn manhattan_distance(&self, other: &Self) -> T
+    where
+        T: Add<Output = T> + Zero<T>,
+    {
+        (0..N).fold(T::zero(), |acc, i| {
+            acc + abs_diff(self.coords[i], other.coords[i])
+        })
+    }
+
+    /// Returns the largest absolute difference of any coordinate.
+    pub fn chebyshev_distance(&self, other: &Self) -> T
+    where
+        T: Zero<T>,
+    {
+        (0..N).fold(T::zero(), |acc, i| {
+            let diff = abs_diff(self.coords[i], other.coords[i]);
+            if diff > acc {
+                diff
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl<T, const N: usize> PointN<T, N>
+where
+    T: Add<Output = T> + Sub<Output = T> + One<T> + Bounded + PartialEq + Copy,
+{
+    /// Returns the `2 * N` points that differ from `self` by one along a single axis. Points that
+    /// would fall outside the range of `T` are left out, so there are fewer at its bounds.
+    pub fn neighbours(&self) -> Vec<Self> {
+        let mut result = Vec::with_capacity(2 * N);
+        for i in 0..N {
+            if self.coords[i] != T::MIN {
+                let mut lower = *self;
+                lower.coords[i] = lower.coords[i] - T::one();
+                result.push(lower);
+            }
+            if self.coords[i] != T::MAX {
+                let mut upper = *self;
+                upper.coords[i] = upper.coords[i] + T::one();
+                result.push(upper);
+            }
+        }
+        result
+    }
+
+    /// Returns the `3^N - 1` points that differ from `self` by at most one along every axis,
+    /// including diagonals. As with [PointN::neighbours], points outside the range of `T` are
+    /// left out.
+    pub fn all_neighbours(&self) -> Vec<Self> {
+        let mut result = vec![*self];
+        for i in 0..N {
+            let mut next = Vec::with_capacity(result.len() * 3);
+            for p in result {
+                if p.coords[i] != T::MIN {
+                    let mut lower = p;
+                    lower.coords[i] = lower.coords[i] - T::one();
+                    next.push(lower);
+                }
+                next.push(p);
+                if p.coords[i] != T::MAX {
+                    let mut upper = p;
+                    upper.coords[i] = upper.coords[i] + T::one();
+                    next.push(upper);
+                }
+            }
+            result = next;
+        }
+        result.retain(|p| p != self);
+        result
+    }
+}
+
+pub(crate) fn abs_diff<T>(a: T, b: T) -> T
+where
+    T: Sub<Output = T> + PartialOrd,
+{
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T, const N: usize> Index<usize> for PointN<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.coords[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for PointN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.coords[index]
+    }
+}
+
+impl<T, const N: usize> Add for PointN<T, N>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        PointN::new(from_fn(|i| self.coords[i] + rhs.coords[i]))
+    }
+}
+
+impl<T, const N: usize> AddAssign for PointN<T, N>
+where
+    T: Add<Output = T> + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T, const N: usize> Sub for PointN<T, N>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        PointN::new(from_fn(|i| self.coords[i] - rhs.coords[i]))
+    }
+}
+
+impl<T, const N: usize> SubAssign for PointN<T, N>
+where
+    T: Sub<Output = T> + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T, const N: usize> Mul<T> for PointN<T, N>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        PointN::new(from_fn(|i| self.coords[i] * rhs))
+    }
+}
+
+impl<T, const N: usize> Neg for PointN<T, N>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        PointN::new(from_fn(|i| -self.coords[i]))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for PointN<T, N> {
+    fn from(value: [T; N]) -> Self {
+        PointN::new(value)
+    }
+}
+
+impl<T, const N: usize> Display for PointN<T, N>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, c) in self.coords.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            c.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returned when text does not describe a point of the expected dimension.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParsePointError {
+    /// The text had `found` comma-separated values, but the point has `expected` coordinates.
+    WrongDimensions { expected: usize, found: usize },
+    /// The coordinate at `index` could not be parsed.
+    InvalidCoordinate { index: usize, value: String },
+}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePointError::WrongDimensions { expected, found } => f.write_fmt(format_args!(
+                "Expected {} coordinates, found {}",
+                expected, found
+            )),
+            ParsePointError::InvalidCoordinate { index, value } => f.write_fmt(format_args!(
+                "Coordinate {} ({:?}) is not a valid number",
+                index, value
+            )),
+        }
+    }
+}
+
+impl Error for ParsePointError {}
+
+/// Parses comma-separated coordinates such as `19, 13, 30`. Whitespace around each coordinate is
+/// ignored.
+impl<T, const N: usize> FromStr for PointN<T, N>
+where
+    T: FromStr,
+{
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(',').map(|p| p.trim()).collect();
+        if parts.len() != N {
+            return Err(ParsePointError::WrongDimensions {
+                expected: N,
+                found: parts.len(),
+            });
+        }
+        let mut coords = Vec::with_capacity(N);
+        for (index, value) in parts.into_iter().enumerate() {
+            let coord = value
+                .parse()
+                .map_err(|_| ParsePointError::InvalidCoordinate {
+                    index,
+                    value: value.to_owned(),
+                })?;
+            coords.push(coord);
+        }
+        match coords.try_into() {
+            Ok(coords) => Ok(PointN::new(coords)),
+            Err(_) => unreachable!("length was checked above"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point_n::{ParsePointError, PointN};
+
+    #[test]
+    fn arithmetic() {
+        let a = PointN::new([1i32, 2, 3, 4]);
+        let b = PointN::new([4, 3, 2, 1]);
+        assert_eq!(a + b, PointN::new([5, 5, 5, 5]));
+        assert_eq!(a - b, PointN::new([-3, -1, 1, 3]));
+        assert_eq!(a * 2, PointN::new([2, 4, 6, 8]));
+        assert_eq!(-a, PointN::new([-1, -2, -3, -4]));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn distances() {
+        let a = PointN::new([0u32, 5, 2]);
+        let b = PointN::new([3, 1, 2]);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(b.manhattan_distance(&a), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = PointN::new([0i32, 0, 0, 0]);
+        assert_eq!(p.neighbours().len(), 8);
+        let all = p.all_neighbours();
+        assert_eq!(all.len(), 80);
+        assert!(!all.contains(&p));
+        assert!(all.iter().all(|n| n.chebyshev_distance(&p) == 1));
+    }
+
+    #[test]
+    fn neighbours_at_bounds() {
+        let origin = PointN::new([0u32, 0]);
+        assert_eq!(
+            origin.neighbours(),
+            vec![PointN::new([1, 0]), PointN::new([0, 1])]
+        );
+        assert_eq!(origin.all_neighbours().len(), 3);
+        let corner = PointN::new([u8::MAX, 0, 5]);
+        assert_eq!(corner.neighbours().len(), 4);
+        assert_eq!(corner.all_neighbours().len(), 2 * 2 * 3 - 1);
+    }
+
+    #[test]
+    fn parse() {
+        let p: PointN<i64, 3> = "19, 13, -30".parse().unwrap();
+        assert_eq!(p, PointN::new([19, 13, -30]));
+        assert_eq!(p.to_string(), "19,13,-30");
+        assert_eq!(
+            "1,2".parse::<PointN<i64, 3>>(),
+            Err(ParsePointError::WrongDimensions {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            "1,x,2".parse::<PointN<i64, 3>>(),
+            Err(ParsePointError::InvalidCoordinate {
+                index: 1,
+                value: "x".to_owned()
+            })
+        );
+    }
+}
@@ -1,22 +1,44 @@
+use crate::scanner::{InputError, ParseError};
+use crate::workspace;
 use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
-use std::str::Lines;
+use std::str::{FromStr, Lines};
 
 #[derive(Debug)]
 pub struct PuzzleInput {
     raw: String,
+    /// Taken from a parent directory named like `aoc-05`, if there is one.
+    day: Option<u8>,
+    /// Taken from the directory above that, if it is named like `2023`.
+    year: Option<u16>,
 }
 
 impl PuzzleInput {
     /// Reads the file provided in `path` and returns something usable by the puzzles.
     /// This panics immediately if the file cannot be read.
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
-        let raw_result = read_to_string(path);
+        let raw_result = read_to_string(&path);
+        let canonical = path.as_ref().canonicalize().ok();
+        let dir_name = |levels: usize| -> Option<String> {
+            let dir = canonical.as_ref()?.ancestors().nth(levels)?;
+            Some(dir.file_name()?.to_str()?.to_owned())
+        };
+        let day = dir_name(1).and_then(|name| name.strip_prefix("aoc-")?.parse().ok());
+        let year = day
+            .and(dir_name(2))
+            .and_then(|name| workspace::parse_year(&name));
         PuzzleInput {
             raw: raw_result.unwrap(),
+            day,
+            year,
         }
     }
 
+    /// Reads the `input.txt` of a day in `year`, from anywhere in the workspace.
+    pub fn for_day(year: u16, day: u8) -> Self {
+        PuzzleInput::new(workspace::day_dir(workspace::root(), year, day).join("input.txt"))
+    }
+
     /// Reads the file named "input.txt" in the directory of the currently executing crate.
     // pub fn new() -> Self {
     //     let basePath = std::env::var("CARGO_PKG_NAME").unwrap();
@@ -37,6 +59,54 @@ impl PuzzleInput {
     pub fn to_lines(&'_ self) -> Lines<'_> {
         self.raw.lines()
     }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn year(&self) -> Option<u16> {
+        self.year
+    }
+
+    /// Parses the whole input as a `T`, naming the day in any error.
+    pub fn parse<T>(&self) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: Into<InputError>,
+    {
+        self.raw.parse().map_err(|e: T::Err| InputError {
+            day: self.day,
+            ..e.into()
+        })
+    }
+
+    /// Parses each line of the input as a `T`. Every line is tried, so the error lists all bad
+    /// lines rather than only the first.
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        let mut result = vec![];
+        let mut errors = vec![];
+        for (i, line) in self.to_lines().enumerate() {
+            match line.parse::<T>() {
+                Ok(value) => result.push(value),
+                // `line` was parsed on its own, so its errors start counting from line 1
+                Err(error) => errors.push(ParseError {
+                    line: error.line + i,
+                    ..error
+                }),
+            }
+        }
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(InputError {
+                day: self.day,
+                errors,
+            })
+        }
+    }
 }
 
 impl Default for PuzzleInput {
@@ -50,12 +120,24 @@ impl Default for PuzzleInput {
 #[cfg(test)]
 mod tests {
     use crate::puzzle_input::PuzzleInput;
+    use crate::scanner::{ParseError, Scanner};
     use std::ops::Index;
+    use std::str::FromStr;
 
     #[test]
     fn can_construct_without_panic() {
         let input = PuzzleInput::new("./input.txt");
         assert!(!input.raw.is_empty());
+        assert_eq!(input.day(), None);
+        assert_eq!(input.year(), None);
+    }
+
+    #[test]
+    fn finds_day_and_year_from_path() {
+        let input = PuzzleInput::for_day(2023, 1);
+        assert!(!input.raw.is_empty());
+        assert_eq!(input.day(), Some(1));
+        assert_eq!(input.year(), Some(2023));
     }
 
     #[test]
@@ -78,4 +160,53 @@ mod tests {
         let lines = input.to_lines();
         assert_eq!(lines.skip(3).next().unwrap(), "4635");
     }
+
+    #[derive(Debug)]
+    struct Reading(Vec<u32>);
+
+    impl FromStr for Reading {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Scanner::parse(s, |s| Ok(Reading(s.many(|s| s.unsigned()))))
+        }
+    }
+
+    #[test]
+    fn parse_lines() {
+        let input = PuzzleInput::new("./input.txt");
+        assert_eq!(input.day(), None);
+        let readings = input.parse_lines::<Reading>().unwrap();
+        assert_eq!(readings[3].0, vec![4635]);
+
+        let input = PuzzleInput {
+            raw: String::from("1\nx\n3\n4 y"),
+            day: Some(1),
+            year: None,
+        };
+        let error = input.parse_lines::<Reading>().unwrap_err();
+        assert_eq!(error.day, Some(1));
+        assert_eq!(error.errors.len(), 2);
+        assert_eq!((error.errors[0].line, error.errors[0].column), (2, 1));
+        assert_eq!((error.errors[1].line, error.errors[1].column), (4, 3));
+    }
+
+    #[test]
+    fn parse() {
+        let input = PuzzleInput {
+            raw: String::from("12"),
+            day: Some(1),
+            year: None,
+        };
+        assert_eq!(input.parse::<Reading>().unwrap().0, vec![12]);
+        let input = PuzzleInput {
+            raw: String::from("12 x"),
+            day: Some(1),
+            year: None,
+        };
+        assert_eq!(
+            input.parse::<Reading>().unwrap_err().to_string(),
+            "Day 1: 1 bad line in puzzle input\n  line 1, column 4: expected end of input, found \"x\""
+        );
+    }
 }
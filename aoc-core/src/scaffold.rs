@@ -0,0 +1,392 @@
+use crate::workspace;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+
+const MANIFEST: &str = r#"[package]
+name = "aoc-@YEAR@-@NN@"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = { path = "../../aoc-core" }
+"#;
+
+const MAIN: &str = r#"mod @MODEL@;
+
+use crate::@MODEL@::@TYPE@;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::scanner::InputError;
+use aoc_core::solution::{run, Solution};
+use std::fmt::Display;
+
+/// <https://adventofcode.com/@YEAR@/day/@DAY@>
+struct Day@NN@;
+
+impl Solution for Day@NN@ {
+    type Input = @TYPE@;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError> {
+        input.parse()
+    }
+
+    fn part_one(@MODEL@: &Self::Input) -> impl Display {
+        @MODEL@.part_one()
+    }
+
+    fn part_two(@MODEL@: &Self::Input) -> impl Display {
+        @MODEL@.part_two()
+    }
+}
+
+fn main() {
+    run::<Day@NN@>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day@NN@;
+    use aoc_core::solution::check_answers;
+
+    #[test]
+    #[ignore = "record the answers with --record-answers first"]
+    fn matches_recorded_answers() {
+        check_answers::<Day@NN@>();
+    }
+}
+"#;
+
+const MODEL: &str = r#"use aoc_core::scanner::{InputError, Scanner};
+use std::str::FromStr;
+
+pub struct @TYPE@ {
+    lines: Vec<String>,
+}
+
+impl @TYPE@ {
+    pub fn part_one(&self) -> u64 {
+        todo!("solve part one from {} lines", self.lines.len())
+    }
+
+    pub fn part_two(&self) -> u64 {
+        todo!("solve part two from {} lines", self.lines.len())
+    }
+}
+
+impl FromStr for @TYPE@ {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = Scanner::new(s).lines(|s| Ok(s.rest_of_line().to_owned()))?;
+        Ok(@TYPE@ { lines })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::@MODEL@::@TYPE@;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    #[ignore = "fill in example.txt and the expected answer"]
+    fn part_one_example() {
+        let @MODEL@: @TYPE@ = EXAMPLE.parse().unwrap();
+        assert_eq!(@MODEL@.part_one(), 0);
+    }
+
+    #[test]
+    #[ignore = "fill in example.txt and the expected answer"]
+    fn part_two_example() {
+        let @MODEL@: @TYPE@ = EXAMPLE.parse().unwrap();
+        assert_eq!(@MODEL@.part_two(), 0);
+    }
+}
+"#;
+
+/// The `main.rs` that the days which weren't started yet were given by hand. It holds no work, so
+/// it may be replaced.
+const HAND_MADE_STUB: &str = r#"use aoc_core::puzzle_input::PuzzleInput;
+
+fn main() {
+    let input = PuzzleInput::default();
+}"#;
+
+/// A new `YYYY/aoc-NN` crate for one day: a manifest, a [Solution](crate::solution::Solution) in
+/// `main.rs` backed by a model module, example tests, and empty `input.txt` and `example.txt`.
+pub struct Scaffold {
+    year: u16,
+    day: u8,
+    /// The name of the model module, in snake case.
+    model: String,
+}
+
+impl Scaffold {
+    pub fn new(year: u16, day: u8, model: &str) -> Result<Self, ScaffoldError> {
+        if !(1..=25).contains(&day) {
+            return Err(ScaffoldError::InvalidDay(day));
+        }
+        let is_snake_case = model.starts_with(|c: char| c.is_ascii_lowercase())
+            && model
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !is_snake_case || model == "main" {
+            return Err(ScaffoldError::InvalidModel(model.to_owned()));
+        }
+        Ok(Scaffold {
+            year,
+            day,
+            model: model.to_owned(),
+        })
+    }
+
+    pub fn dir<P: AsRef<Path>>(&self, root: P) -> PathBuf {
+        workspace::day_dir(root, self.year, self.day)
+    }
+
+    /// The model module's type, e.g. `PipeComplex` for `pipe_complex`.
+    pub fn type_name(&self) -> String {
+        self.model
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Every file to create, relative to the crate's directory.
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        let fill = |template: &str| {
+            template
+                .replace("@NN@", &format!("{:02}", self.day))
+                .replace("@DAY@", &self.day.to_string())
+                .replace("@YEAR@", &self.year.to_string())
+                .replace("@MODEL@", &self.model)
+                .replace("@TYPE@", &self.type_name())
+        };
+        vec![
+            (PathBuf::from("Cargo.toml"), fill(MANIFEST)),
+            (PathBuf::from("src/main.rs"), fill(MAIN)),
+            (PathBuf::from(format!("src/{}.rs", self.model)), fill(MODEL)),
+            (PathBuf::from("input.txt"), String::new()),
+            (PathBuf::from("example.txt"), String::new()),
+        ]
+    }
+
+    /// Creates the crate under `root` and makes sure the workspace there includes its year,
+    /// returning the files that were written. Nothing is written if any file already holds work,
+    /// which is anything but an empty file or the hand-made stub `main.rs`. A non-empty
+    /// `input.txt` is kept as it is.
+    pub fn create<P: AsRef<Path>>(&self, root: P) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let root = root.as_ref();
+        let dir = self.dir(root);
+        let mut pending = Vec::new();
+        let mut conflicts = Vec::new();
+        for (file, contents) in self.files() {
+            let path = dir.join(file);
+            match read_to_string(&path) {
+                Ok(existing) if existing == contents => {}
+                Ok(existing) if contents.is_empty() && !existing.is_empty() => {}
+                Ok(existing) if existing.trim().is_empty() || existing.trim() == HAND_MADE_STUB => {
+                    pending.push((path, contents))
+                }
+                Ok(_) => conflicts.push(path),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => pending.push((path, contents)),
+                Err(e) => return Err(ScaffoldError::Io(path, e)),
+            }
+        }
+        if !conflicts.is_empty() {
+            return Err(ScaffoldError::WouldOverwrite(conflicts));
+        }
+
+        for (path, contents) in &pending {
+            let parent = path.parent().unwrap();
+            create_dir_all(parent).map_err(|e| ScaffoldError::Io(parent.to_path_buf(), e))?;
+            write(path, contents).map_err(|e| ScaffoldError::Io(path.clone(), e))?;
+        }
+        let manifest = root.join("Cargo.toml");
+        let member = format!("{}/aoc-*", self.year);
+        register(&manifest, &member).map_err(|e| ScaffoldError::Io(manifest, e))?;
+        Ok(pending.into_iter().map(|(path, _)| path).collect())
+    }
+}
+
+/// Adds `member` to the workspace members in `manifest`, keeping the years after `aoc-core` in
+/// order. Does nothing if it is already there.
+fn register(manifest: &Path, member: &str) -> io::Result<()> {
+    let contents = read_to_string(manifest)?;
+    let registered = add_member(&contents, member)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no workspace members list"))?;
+    if registered != contents {
+        write(manifest, registered)?;
+    }
+    Ok(())
+}
+
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let lines: Vec<&str> = manifest.lines().collect();
+    let start = lines.iter().position(|l| l.trim() == "members = [")?;
+    let end = start + lines[start..].iter().position(|l| l.trim() == "]")?;
+    let quoted = format!("\"{}\"", member);
+    let members: Vec<&str> = lines[start + 1..end]
+        .iter()
+        .map(|l| l.trim().trim_end_matches(','))
+        .collect();
+    if members.contains(&quoted.as_str()) {
+        return Some(manifest.to_owned());
+    }
+
+    // Members are quoted, so they compare the same as their names
+    let index = members
+        .iter()
+        .position(|m| *m != "\"aoc-core\"" && *m > quoted.as_str())
+        .map_or(end, |i| start + 1 + i);
+    let mut result: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    result.insert(index, format!("    {},", quoted));
+    Some(result.join("\n") + "\n")
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// Puzzles only exist for days 1 to 25.
+    InvalidDay(u8),
+    /// The model name can't be used as a module name.
+    InvalidModel(String),
+    /// These files already hold work.
+    WouldOverwrite(Vec<PathBuf>),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => f.write_fmt(format_args!("There is no day {}", day)),
+            ScaffoldError::InvalidModel(model) => {
+                f.write_fmt(format_args!("{:?} isn't a snake case module name", model))
+            }
+            ScaffoldError::WouldOverwrite(paths) => {
+                f.write_str("Refusing to overwrite existing work in:")?;
+                for path in paths {
+                    f.write_fmt(format_args!("\n  {}", path.display()))?;
+                }
+                Ok(())
+            }
+            ScaffoldError::Io(path, e) => f.write_fmt(format_args!("{}: {}", path.display(), e)),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{add_member, Scaffold, ScaffoldError, HAND_MADE_STUB};
+    use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+    use std::path::PathBuf;
+
+    const WORKSPACE: &str = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc-core\",\n    \"2015/aoc-*\",\n    \"2023/aoc-*\",\n]\n";
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", std::process::id(), name));
+        let _ = remove_dir_all(&root);
+        create_dir_all(&root).unwrap();
+        write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        root
+    }
+
+    #[test]
+    fn validates_names() {
+        assert_eq!(
+            Scaffold::new(2023, 11, "galaxy_map_2").unwrap().type_name(),
+            "GalaxyMap2"
+        );
+        assert!(matches!(
+            Scaffold::new(2023, 26, "x"),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+        for model in ["GalaxyMap", "2d", "main", "galaxy-map", ""] {
+            assert!(Scaffold::new(2023, 11, model).is_err(), "{}", model);
+        }
+    }
+
+    #[test]
+    fn adds_members_in_order() {
+        let added = add_member(WORKSPACE, "2022/aoc-*").unwrap();
+        assert!(added.contains(
+            "\"aoc-core\",\n    \"2015/aoc-*\",\n    \"2022/aoc-*\",\n    \"2023/aoc-*\","
+        ));
+        let appended = add_member(WORKSPACE, "2024/aoc-*").unwrap();
+        assert!(appended.ends_with("\"2023/aoc-*\",\n    \"2024/aoc-*\",\n]\n"));
+        assert_eq!(add_member(WORKSPACE, "2023/aoc-*").unwrap(), WORKSPACE);
+        assert_eq!(add_member("[package]\n", "2022/aoc-*"), None);
+    }
+
+    #[test]
+    fn creates_a_day() {
+        let root = temp_root("create");
+        let scaffold = Scaffold::new(2022, 2, "cube_bag").unwrap();
+        let written = scaffold.create(&root).unwrap();
+        assert_eq!(written.len(), 5);
+
+        let main = read_to_string(root.join("2022/aoc-02/src/main.rs")).unwrap();
+        assert!(main.starts_with("mod cube_bag;\n\nuse crate::cube_bag::CubeBag;"));
+        assert!(main.contains("/// <https://adventofcode.com/2022/day/2>\nstruct Day02;"));
+        let model = read_to_string(root.join("2022/aoc-02/src/cube_bag.rs")).unwrap();
+        assert!(model.contains("impl FromStr for CubeBag {"));
+        assert_eq!(
+            read_to_string(root.join("2022/aoc-02/example.txt")).unwrap(),
+            ""
+        );
+        let manifest = read_to_string(root.join("2022/aoc-02/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"aoc-2022-02\""));
+        let workspace = read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"2015/aoc-*\",\n    \"2022/aoc-*\",\n    \"2023/aoc-*\","));
+
+        // Running it again changes nothing
+        assert!(scaffold.create(&root).unwrap().is_empty());
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn replaces_stubs_but_not_work() {
+        let root = temp_root("stub");
+        create_dir_all(root.join("2023/aoc-11/src")).unwrap();
+        write(
+            root.join("2023/aoc-11/src/main.rs"),
+            HAND_MADE_STUB.to_owned() + "\n",
+        )
+        .unwrap();
+        write(root.join("2023/aoc-11/input.txt"), "...#\n").unwrap();
+        let scaffold = Scaffold::new(2023, 11, "galaxy_map").unwrap();
+        scaffold.create(&root).unwrap();
+        assert!(read_to_string(root.join("2023/aoc-11/src/main.rs"))
+            .unwrap()
+            .contains("struct Day11;"));
+        assert_eq!(
+            read_to_string(root.join("2023/aoc-11/input.txt")).unwrap(),
+            "...#\n"
+        );
+
+        write(root.join("2023/aoc-11/src/galaxy_map.rs"), "// solved it\n").unwrap();
+        match Scaffold::new(2023, 11, "galaxy_map").unwrap().create(&root) {
+            Err(ScaffoldError::WouldOverwrite(paths)) => {
+                assert_eq!(paths, vec![root.join("2023/aoc-11/src/galaxy_map.rs")])
+            }
+            other => panic!("Expected a refusal, got {:?}", other),
+        }
+        assert_eq!(
+            read_to_string(root.join("2023/aoc-11/src/galaxy_map.rs")).unwrap(),
+            "// solved it\n"
+        );
+        remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,489 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An error raised while scanning input. `line` and `column` are 1-based and point at the start
+/// of the offending token.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// What the parser was looking for, e.g. `u8` or `"Game"`.
+    pub expected: String,
+    /// What was there instead, e.g. `"300"` or `end of line`.
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        ))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Every bad line found in a day's puzzle input, so they can all be fixed in one go.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputError {
+    /// The day whose input this is, if known.
+    pub day: Option<u8>,
+    pub errors: Vec<ParseError>,
+}
+
+impl InputError {
+    /// Prints every error to stderr and exits the process with a failure code.
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self);
+        std::process::exit(1)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(value: ParseError) -> Self {
+        InputError {
+            day: None,
+            errors: vec![value],
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            f.write_fmt(format_args!("Day {}: ", day))?;
+        }
+        let plural = if self.errors.len() == 1 { "" } else { "s" };
+        f.write_fmt(format_args!(
+            "{} bad line{} in puzzle input",
+            self.errors.len(),
+            plural
+        ))?;
+        for error in self.errors.iter() {
+            f.write_fmt(format_args!("\n  {}", error))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputError {}
+
+/// A cursor over puzzle input that reads one token at a time and keeps track of where it is.
+///
+/// Token readers ([Scanner::literal], [Scanner::unsigned], [Scanner::signed], [Scanner::word])
+/// skip any spaces and tabs in front of the token, but never line breaks. Character readers
+/// ([Scanner::char], [Scanner::one_of]) read exactly the next character, which suits grids.
+///
+/// As a scanner is `Copy`, a copy taken before an attempt can be used to rewind, or to report an
+/// error at the place the attempt started.
+#[derive(Clone, Copy, Debug)]
+pub struct Scanner<'a> {
+    input: &'a str,
+    /// Byte offset of the next character to read.
+    offset: usize,
+    line: usize,
+    /// Byte offset of the start of the current line.
+    line_start: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Scanner::at_line(input, 1)
+    }
+
+    /// Creates a scanner for input that starts on `line` of a larger text, so errors point at the
+    /// right line of the whole puzzle input.
+    pub fn at_line(input: &'a str, line: usize) -> Self {
+        Scanner {
+            input,
+            offset: 0,
+            line,
+            line_start: 0,
+        }
+    }
+
+    /// Runs `parser` over the whole of `input`, failing if anything but whitespace is left over.
+    pub fn parse<T>(
+        input: &'a str,
+        parser: impl FnOnce(&mut Scanner<'a>) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let mut scanner = Scanner::new(input);
+        let result = parser(&mut scanner)?;
+        scanner.end()?;
+        Ok(result)
+    }
+
+    /// Like [Scanner::parse], for a single line taken from `line` of a larger text.
+    pub fn parse_line<T>(
+        input: &'a str,
+        line: usize,
+        parser: impl FnOnce(&mut Scanner<'a>) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let mut scanner = Scanner::at_line(input, line);
+        let result = parser(&mut scanner)?;
+        scanner.end()?;
+        Ok(result)
+    }
+
+    /// Returns the 1-based line and column of the next character.
+    pub fn position(&self) -> (usize, usize) {
+        let column = self.input[self.line_start..self.offset].chars().count() + 1;
+        (self.line, column)
+    }
+
+    /// Returns everything that has not been read yet.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.input.len()
+    }
+
+    pub fn is_at_line_end(&self) -> bool {
+        let rest = self.rest();
+        rest.is_empty() || rest.starts_with('\n') || rest.starts_with("\r\n")
+    }
+
+    /// Skips spaces and tabs.
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    /// Skips any whitespace, including line breaks.
+    pub fn skip_newlines(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Reads characters for as long as `predicate` holds, possibly none.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.advance(len);
+        &rest[..len]
+    }
+
+    /// Reads the rest of the current line, without the line break.
+    pub fn rest_of_line(&mut self) -> &'a str {
+        self.take_while(|c| c != '\n' && c != '\r')
+    }
+
+    /// Reads the next character, which may not be a line break.
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) if !self.is_at_line_end() => {
+                self.advance(c.len_utf8());
+                Ok(c)
+            }
+            _ => Err(self.error("a character")),
+        }
+    }
+
+    /// Reads the next character if it is one of `chars`. Unlike other errors, a failure reports
+    /// only the offending character, rather than the whole token.
+    pub fn one_of(&mut self, chars: &str) -> Result<char, ParseError> {
+        match self.peek().filter(|_| !self.is_at_line_end()) {
+            Some(c) if chars.contains(c) => {
+                self.advance(c.len_utf8());
+                Ok(c)
+            }
+            Some(c) => {
+                let (line, column) = self.position();
+                Err(ParseError {
+                    line,
+                    column,
+                    expected: format!("one of {}", chars),
+                    found: format!("\"{}\"", c),
+                })
+            }
+            None => Err(self.error(&format!("one of {}", chars))),
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.try_literal(literal) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("\"{}\"", literal)))
+        }
+    }
+
+    /// Reads `literal` if it comes next, returning whether it did.
+    pub fn try_literal(&mut self, literal: &str) -> bool {
+        let mut next = *self;
+        next.skip_whitespace();
+        if next.rest().starts_with(literal) {
+            next.advance(literal.len());
+            *self = next;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads a run of ASCII digits.
+    pub fn digits(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        match self.take_while(|c| c.is_ascii_digit()) {
+            "" => Err(self.error("digits")),
+            digits => Ok(digits),
+        }
+    }
+
+    /// Reads an integer without a sign.
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = *self;
+        self.digits()
+            .ok()
+            .and_then(|digits| digits.parse().ok())
+            .ok_or_else(|| start.error(type_name::<T>()))
+    }
+
+    /// Reads an integer with an optional leading `-` or `+`.
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = *self;
+        let sign = self.take_while(|c| c == '-' || c == '+');
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if sign.len() > 1 || digits.is_empty() {
+            *self = start;
+            return Err(self.error("signed integer"));
+        }
+        start.input[start.offset..self.offset]
+            .parse()
+            .map_err(|_| start.error(type_name::<T>()))
+    }
+
+    /// Reads a run of letters, digits and underscores.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        match self.take_while(|c| c.is_alphanumeric() || c == '_') {
+            "" => Err(self.error("a word")),
+            word => Ok(word),
+        }
+    }
+
+    /// Reads a line break, or accepts the end of the input.
+    pub fn line_end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.is_at_end() || self.try_literal("\n") || self.try_literal("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    /// Accepts the end of the input, after any trailing whitespace.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_newlines();
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of input"))
+        }
+    }
+
+    /// Reads one or more items with `separator` between each of them. Spaces and tabs are skipped
+    /// before the separator, so it can't itself be whitespace; use [Scanner::many] for items that
+    /// are only separated by spaces.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut result = vec![item(self)?];
+        while self.try_literal(separator) {
+            result.push(item(self)?);
+        }
+        Ok(result)
+    }
+
+    /// Reads items for as long as `item` succeeds, possibly none. A failed attempt is rewound, so
+    /// the input it stopped at can still be read by whatever comes next.
+    pub fn many<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Vec<T> {
+        let mut result = vec![];
+        loop {
+            let checkpoint = *self;
+            match item(self) {
+                Ok(value) if self.offset > checkpoint.offset => result.push(value),
+                _ => {
+                    *self = checkpoint;
+                    return result;
+                }
+            }
+        }
+    }
+
+    /// Reads one item per line until the end of the input. Blank lines at the end are ignored.
+    ///
+    /// A line that fails to parse is skipped and scanning carries on with the next one, so the
+    /// error lists every bad line rather than only the first.
+    pub fn lines<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, InputError> {
+        let mut result = vec![];
+        let mut errors = vec![];
+        loop {
+            let mut rest = *self;
+            rest.skip_newlines();
+            if rest.is_at_end() {
+                *self = rest;
+                break;
+            }
+            match item(self).and_then(|value| self.line_end().map(|_| value)) {
+                Ok(value) => result.push(value),
+                Err(error) => {
+                    errors.push(error);
+                    self.rest_of_line();
+                    self.try_literal("\r");
+                    self.try_literal("\n");
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(InputError { day: None, errors })
+        }
+    }
+
+    /// Returns an error for the token at the current position, after any spaces and tabs.
+    pub fn error(&self, expected: &str) -> ParseError {
+        let mut at = *self;
+        at.skip_whitespace();
+        let (line, column) = at.position();
+        let found = if at.is_at_end() {
+            String::from("end of input")
+        } else if at.is_at_line_end() {
+            String::from("end of line")
+        } else {
+            format!("\"{}\"", at.take_while(|c| !c.is_whitespace()))
+        };
+        ParseError {
+            line,
+            column,
+            expected: expected.to_owned(),
+            found,
+        }
+    }
+
+    fn advance(&mut self, len: usize) {
+        let skipped = &self.input[self.offset..self.offset + len];
+        if let Some(last_break) = skipped.rfind('\n') {
+            self.line += skipped.matches('\n').count();
+            self.line_start = self.offset + last_break + 1;
+        }
+        self.offset += len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scanner::{InputError, ParseError, Scanner};
+
+    #[test]
+    fn tokens() {
+        let mut s = Scanner::new("Game 12: -3 +4 red_1 |x");
+        assert!(s.literal("Game").is_ok());
+        assert_eq!(s.unsigned::<u8>(), Ok(12));
+        assert!(s.literal(":").is_ok());
+        assert_eq!(s.signed::<i32>(), Ok(-3));
+        assert_eq!(s.signed::<i32>(), Ok(4));
+        assert_eq!(s.word(), Ok("red_1"));
+        assert!(!s.try_literal("#"));
+        assert_eq!(s.one_of("|#").unwrap_err().found, "\" \"");
+        s.skip_whitespace();
+        assert_eq!(s.one_of("|#"), Ok('|'));
+        assert_eq!(s.char(), Ok('x'));
+        assert!(s.is_at_end());
+        assert!(s.end().is_ok());
+    }
+
+    #[test]
+    fn errors_point_at_token() {
+        let mut s = Scanner::new("1 2\n3 300 4");
+        assert_eq!(s.many(|s| s.unsigned::<u8>()), vec![1, 2]);
+        assert!(s.line_end().is_ok());
+        assert_eq!(s.rest().len(), 7);
+        let mut copy = s;
+        assert_eq!(copy.rest_of_line(), "3 300 4");
+        assert_eq!(s.unsigned::<u8>(), Ok(3));
+        assert_eq!(
+            s.unsigned::<u8>(),
+            Err(ParseError {
+                line: 2,
+                column: 3,
+                expected: String::from("u8"),
+                found: String::from("\"300\""),
+            })
+        );
+        assert_eq!(
+            s.literal(",").unwrap_err().to_string(),
+            "line 2, column 7: expected \",\", found \"4\""
+        );
+    }
+
+    #[test]
+    fn at_line() {
+        let error = Scanner::parse_line("ab", 7, |s| s.unsigned::<u8>()).unwrap_err();
+        assert_eq!((error.line, error.column), (7, 1));
+        assert_eq!(error.found, "\"ab\"");
+    }
+
+    #[test]
+    fn separated_and_lines() {
+        let mut s = Scanner::new("1, 2; 3\n4\n\n");
+        let result = s.lines(|s| s.separated(";", |s| s.separated(",", |s| s.unsigned::<u8>())));
+        assert_eq!(result, Ok(vec![vec![vec![1, 2], vec![3]], vec![vec![4]]]));
+        assert!(s.is_at_end());
+    }
+
+    #[test]
+    fn lines_reports_every_bad_line() {
+        let mut s = Scanner::new("1,2\n3,\n4\nx,5 \n");
+        let error = s
+            .lines(|s| s.separated(",", |s| s.unsigned::<u8>()))
+            .unwrap_err();
+        assert_eq!(error.errors.len(), 2);
+        assert_eq!((error.errors[0].line, error.errors[0].column), (2, 3));
+        assert_eq!(error.errors[0].found, "end of line");
+        assert_eq!((error.errors[1].line, error.errors[1].column), (4, 1));
+        assert!(s.is_at_end());
+
+        let error = InputError {
+            day: Some(2),
+            ..error
+        };
+        assert_eq!(
+            error.to_string(),
+            "Day 2: 2 bad lines in puzzle input\n  \
+             line 2, column 3: expected u8, found end of line\n  \
+             line 4, column 1: expected u8, found \"x,5\""
+        );
+    }
+
+    #[test]
+    fn signed_rejects_bare_sign() {
+        let mut s = Scanner::new("- 5");
+        assert_eq!(s.signed::<i32>().unwrap_err().expected, "signed integer");
+        assert_eq!(s.position(), (1, 1));
+    }
+
+    #[test]
+    fn trailing_input() {
+        let error = Scanner::parse("5 x", |s| s.unsigned::<u8>()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected end of input, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,441 @@
+use crate::includes::Includes;
+use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
+use std::array::from_fn;
+
+/// A set of small non-negative integers, stored as one bit per value in `N` 64-bit words. Values
+/// must be below `64 * N`.
+///
+/// Being `Copy` and `Hash`, it is cheap to use as (part of) a memoization key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BitSet<const N: usize> {
+    words: [u64; N],
+}
+
+/// A [BitSet] holding values below 64.
+pub type BitSet64 = BitSet<1>;
+
+/// A [BitSet] holding values below 128.
+pub type BitSet128 = BitSet<2>;
+
+impl<const N: usize> BitSet<N> {
+    /// The number of distinct values the set can hold.
+    pub const CAPACITY: usize = 64 * N;
+
+    pub fn new() -> Self {
+        BitSet { words: [0; N] }
+    }
+
+    /// Adds `value` to the set, returning whether it was newly inserted.
+    ///
+    /// Panics if `value` is not below [BitSet::CAPACITY].
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, mask) = Self::locate(value);
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        inserted
+    }
+
+    /// Removes `value` from the set, returning whether it was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        if value >= Self::CAPACITY {
+            return false;
+        }
+        let (word, mask) = Self::locate(value);
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        removed
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        if value >= Self::CAPACITY {
+            return false;
+        }
+        let (word, mask) = Self::locate(value);
+        self.words[word] & mask != 0
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words = [0; N];
+    }
+
+    /// Iterates over the values in the set, in ascending order.
+    pub fn iter(&self) -> Bits<'_> {
+        Bits::new(&self.words)
+    }
+
+    fn locate(value: usize) -> (usize, u64) {
+        assert!(
+            value < Self::CAPACITY,
+            "{} does not fit in a BitSet of capacity {}",
+            value,
+            Self::CAPACITY
+        );
+        (value / 64, 1 << (value % 64))
+    }
+
+    fn zip_with(&self, rhs: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        BitSet {
+            words: from_fn(|i| f(self.words[i], rhs.words[i])),
+        }
+    }
+}
+
+impl<const N: usize> Default for BitSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for BitSet<N> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a BitSet<N> {
+    type Item = usize;
+    type IntoIter = Bits<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> SetDifference<Self> for BitSet<N> {
+    fn difference(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a & !b)
+    }
+}
+
+impl<const N: usize> SetIntersection<Self> for BitSet<N> {
+    fn intersection(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a & b)
+    }
+}
+
+impl<const N: usize> SetUnion<Self> for BitSet<N> {
+    fn union(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a | b)
+    }
+}
+
+impl<const N: usize> SetSymmetricDifference<Self> for BitSet<N> {
+    fn symmetric_difference(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a ^ b)
+    }
+}
+
+impl<const N: usize> Includes<usize> for BitSet<N> {
+    fn includes(&self, other: &usize) -> bool {
+        self.contains(*other)
+    }
+}
+
+impl<const N: usize> Includes<Self> for BitSet<N> {
+    fn includes(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+}
+
+/// A set of non-negative integers, stored as one bit per value, that grows to fit the largest
+/// value inserted.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct GrowableBitSet {
+    /// Never ends in a zero word, so equal sets have equal words (and hashes).
+    words: Vec<u64>,
+}
+
+impl GrowableBitSet {
+    pub fn new() -> Self {
+        GrowableBitSet { words: vec![] }
+    }
+
+    /// Adds `value` to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, mask) = (value / 64, 1 << (value % 64));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        inserted
+    }
+
+    /// Removes `value` from the set, returning whether it was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (word, mask) = (value / 64, 1 << (value % 64));
+        let removed = self.words.get(word).is_some_and(|w| w & mask != 0);
+        if removed {
+            self.words[word] &= !mask;
+            self.trim();
+        }
+        removed
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.words
+            .get(value / 64)
+            .is_some_and(|w| w & (1 << (value % 64)) != 0)
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// Iterates over the values in the set, in ascending order.
+    pub fn iter(&self) -> Bits<'_> {
+        Bits::new(&self.words)
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    fn zip_with(&self, rhs: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let len = self.words.len().max(rhs.words.len());
+        let word = |words: &[u64], i: usize| words.get(i).copied().unwrap_or(0);
+        let mut result = GrowableBitSet {
+            words: (0..len)
+                .map(|i| f(word(&self.words, i), word(&rhs.words, i)))
+                .collect(),
+        };
+        result.trim();
+        result
+    }
+}
+
+impl FromIterator<usize> for GrowableBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = GrowableBitSet::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a GrowableBitSet {
+    type Item = usize;
+    type IntoIter = Bits<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> From<BitSet<N>> for GrowableBitSet {
+    fn from(value: BitSet<N>) -> Self {
+        value.iter().collect()
+    }
+}
+
+impl SetDifference<Self> for GrowableBitSet {
+    fn difference(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a & !b)
+    }
+}
+
+impl SetIntersection<Self> for GrowableBitSet {
+    fn intersection(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a & b)
+    }
+}
+
+impl SetUnion<Self> for GrowableBitSet {
+    fn union(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a | b)
+    }
+}
+
+impl SetSymmetricDifference<Self> for GrowableBitSet {
+    fn symmetric_difference(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| a ^ b)
+    }
+}
+
+impl Includes<usize> for GrowableBitSet {
+    fn includes(&self, other: &usize) -> bool {
+        self.contains(*other)
+    }
+}
+
+impl Includes<Self> for GrowableBitSet {
+    fn includes(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+}
+
+/// Iterates over the values of a [BitSet] or [GrowableBitSet], in ascending order.
+pub struct Bits<'a> {
+    words: &'a [u64],
+    /// The index of `current` in `words`.
+    index: usize,
+    /// The bits of the current word that have not been yielded yet.
+    current: u64,
+}
+
+impl<'a> Bits<'a> {
+    fn new(words: &'a [u64]) -> Self {
+        Bits {
+            words,
+            index: 0,
+            current: words.first().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl Iterator for Bits<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.index * 64 + bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::includes::Includes;
+    use crate::set::bit_set::{BitSet, BitSet128, BitSet64, GrowableBitSet};
+    use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
+    use std::collections::HashSet;
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set: BitSet<2> = BitSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(0));
+        assert!(set.insert(127));
+        assert!(!set.insert(127));
+        assert!(set.contains(0));
+        assert!(set.contains(127));
+        assert!(!set.contains(64));
+        assert!(!set.contains(128));
+        assert!(set.remove(0));
+        assert!(!set.remove(0));
+        assert!(!set.remove(500));
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_capacity() {
+        let mut set: BitSet<1> = BitSet::new();
+        set.insert(64);
+    }
+
+    #[test]
+    fn set_operations() {
+        let set_a: BitSet<2> = [1, 2, 70, 100].into_iter().collect();
+        let set_b: BitSet<2> = [2, 3, 100].into_iter().collect();
+        assert_eq!(set_a.difference(&set_b), [1, 70].into_iter().collect());
+        assert_eq!(set_a.intersection(&set_b), [2, 100].into_iter().collect());
+        assert_eq!(
+            set_a.union(&set_b),
+            [1, 2, 3, 70, 100].into_iter().collect()
+        );
+        assert_eq!(
+            set_a.symmetric_difference(&set_b),
+            [1, 3, 70].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn includes() {
+        let set: BitSet<1> = [1, 2, 3].into_iter().collect();
+        assert!(set.includes(&2));
+        assert!(!set.includes(&4));
+        assert!(set.includes(&[1, 3].into_iter().collect::<BitSet<1>>()));
+        assert!(!set.includes(&[1, 4].into_iter().collect::<BitSet<1>>()));
+    }
+
+    #[test]
+    fn len_and_iter() {
+        let set: BitSet128 = [127, 0, 64, 63, 5].into_iter().collect();
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 5, 63, 64, 127]);
+        assert_eq!(BitSet64::new().iter().next(), None);
+        let total: usize = (&set).into_iter().sum();
+        assert_eq!(total, 259);
+    }
+
+    #[test]
+    fn hashing() {
+        let mut seen = HashSet::new();
+        assert!(seen.insert([1, 2].into_iter().collect::<BitSet64>()));
+        assert!(!seen.insert([2, 1].into_iter().collect::<BitSet64>()));
+
+        let mut grown = GrowableBitSet::new();
+        grown.insert(1000);
+        grown.insert(2);
+        grown.remove(1000);
+        let mut seen = HashSet::new();
+        assert!(seen.insert(grown));
+        assert!(!seen.insert([2].into_iter().collect::<GrowableBitSet>()));
+    }
+
+    #[test]
+    fn growable() {
+        let mut set = GrowableBitSet::new();
+        assert!(set.insert(3));
+        assert!(set.insert(300));
+        assert!(!set.insert(300));
+        assert!(set.contains(300));
+        assert!(!set.contains(10_000));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 300]);
+        assert!(set.remove(300));
+        assert!(!set.remove(10_000));
+        assert_eq!(
+            set,
+            GrowableBitSet::from([3].into_iter().collect::<BitSet64>())
+        );
+    }
+
+    #[test]
+    fn growable_set_operations() {
+        let set_a: GrowableBitSet = [1, 2, 200].into_iter().collect();
+        let set_b: GrowableBitSet = [2, 3].into_iter().collect();
+        assert_eq!(set_a.difference(&set_b), [1, 200].into_iter().collect());
+        assert_eq!(set_a.intersection(&set_b), [2].into_iter().collect());
+        assert_eq!(set_a.union(&set_b), [1, 2, 3, 200].into_iter().collect());
+        assert_eq!(
+            set_a.symmetric_difference(&set_b),
+            [1, 3, 200].into_iter().collect()
+        );
+        assert!(set_a.includes(&200));
+        assert!(!set_b.includes(&set_a));
+        assert!(set_a.union(&set_b).includes(&set_a));
+    }
+}
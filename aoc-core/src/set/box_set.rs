@@ -0,0 +1,326 @@
+use crate::includes::Includes;
+use crate::num::{One, Zero};
+use crate::overlaps::Overlaps;
+use crate::point_n::PointN;
+use crate::set::{SetDifference, SetIntersection, SetUnion};
+use std::array::from_fn;
+use std::ops::{Add, Mul, RangeInclusive, Sub};
+
+/// An axis-aligned box in `N` dimensions, holding an inclusive range of values along each axis.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Hyperrect<T, const N: usize> {
+    axes: [RangeInclusive<T>; N],
+}
+
+impl<T, const N: usize> Hyperrect<T, N>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    /// Returns `None` if the range along any axis is empty.
+    pub fn new(axes: [RangeInclusive<T>; N]) -> Option<Self> {
+        if axes.iter().any(|a| a.is_empty()) {
+            None
+        } else {
+            Some(Hyperrect { axes })
+        }
+    }
+
+    pub fn axis(&self, i: usize) -> &RangeInclusive<T> {
+        &self.axes[i]
+    }
+
+    /// Returns the number of points inside the box.
+    pub fn volume(&self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.axes
+            .iter()
+            .fold(T::one(), |acc, a| acc * (*a.end() - *a.start() + T::one()))
+    }
+
+    /// Returns the box covered by both `self` and `other`, if they overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(Hyperrect {
+            axes: from_fn(|i| {
+                *self.axes[i].start().max(other.axes[i].start())
+                    ..=*self.axes[i].end().min(other.axes[i].end())
+            }),
+        })
+    }
+
+    /// Returns disjoint boxes covering every point of `self` that is not in `other`. At most
+    /// `2 * N` boxes are returned.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+        let mut result = vec![];
+        let mut remaining = self.clone();
+        // slice off the parts outside `other` one axis at a time, shrinking what's left
+        for i in 0..N {
+            let (start, end) = (*remaining.axes[i].start(), *remaining.axes[i].end());
+            let (other_start, other_end) = (*other.axes[i].start(), *other.axes[i].end());
+            if start < other_start {
+                let mut slab = remaining.clone();
+                slab.axes[i] = start..=other_start - T::one();
+                result.push(slab);
+                remaining.axes[i] = other_start..=end;
+            }
+            if end > other_end {
+                let mut slab = remaining.clone();
+                slab.axes[i] = other_end + T::one()..=end;
+                result.push(slab);
+                remaining.axes[i] = *remaining.axes[i].start()..=other_end;
+            }
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Overlaps<Hyperrect<T, N>> for Hyperrect<T, N>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &Hyperrect<T, N>) -> bool {
+        self.axes
+            .iter()
+            .zip(other.axes.iter())
+            .all(|(a, b)| a.overlaps(b))
+    }
+}
+
+impl<T, const N: usize> Includes<Hyperrect<T, N>> for Hyperrect<T, N>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &Hyperrect<T, N>) -> bool {
+        self.axes
+            .iter()
+            .zip(other.axes.iter())
+            .all(|(a, b)| a.includes(b))
+    }
+}
+
+impl<T, const N: usize> Includes<PointN<T, N>> for Hyperrect<T, N>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &PointN<T, N>) -> bool {
+        self.axes
+            .iter()
+            .zip(other.coords.iter())
+            .all(|(a, c)| a.contains(c))
+    }
+}
+
+/// A set of points in `N` dimensions, stored as disjoint [Hyperrect]s.
+///
+/// The same points can be split into boxes in many ways, so two sets compare equal when they hold
+/// the same points, whichever boxes they happen to be stored as.
+#[derive(Clone, Debug)]
+pub struct BoxSet<T, const N: usize> {
+    boxes: Vec<Hyperrect<T, N>>,
+}
+
+impl<T, const N: usize> BoxSet<T, N>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(boxes: Vec<Hyperrect<T, N>>) -> Self {
+        boxes
+            .into_iter()
+            .fold(BoxSet { boxes: vec![] }, |mut acc, b| {
+                acc.insert(b);
+                acc
+            })
+    }
+
+    /// Adds every point in `b` to the set.
+    pub fn insert(&mut self, b: Hyperrect<T, N>) {
+        // only the parts of `b` not already covered are stored, keeping the boxes disjoint
+        let mut pieces = vec![b];
+        for existing in self.boxes.iter() {
+            pieces = pieces
+                .into_iter()
+                .flat_map(|p| p.difference(existing))
+                .collect();
+        }
+        self.boxes.append(&mut pieces);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Hyperrect<T, N>> {
+        self.boxes.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// Returns the number of points in the set.
+    pub fn volume(&self) -> T
+    where
+        T: Mul<Output = T> + Zero<T>,
+    {
+        self.boxes.iter().fold(T::zero(), |acc, b| acc + b.volume())
+    }
+}
+
+impl<T, const N: usize> From<Hyperrect<T, N>> for BoxSet<T, N> {
+    fn from(value: Hyperrect<T, N>) -> Self {
+        BoxSet { boxes: vec![value] }
+    }
+}
+
+impl<T, const N: usize> PartialEq for BoxSet<T, N>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.difference(other).is_empty() && other.difference(self).is_empty()
+    }
+}
+
+impl<T, const N: usize> Eq for BoxSet<T, N> where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>
+{
+}
+
+impl<T, const N: usize> SetDifference<Self> for BoxSet<T, N>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn difference(&self, rhs: &Self) -> Self {
+        let mut boxes = self.boxes.clone();
+        for r in rhs.boxes.iter() {
+            boxes = boxes.into_iter().flat_map(|b| b.difference(r)).collect();
+        }
+        BoxSet { boxes }
+    }
+}
+
+impl<T, const N: usize> SetIntersection<Self> for BoxSet<T, N>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn intersection(&self, rhs: &Self) -> Self {
+        // both sides are disjoint, so the pairwise intersections are too
+        let boxes = self
+            .boxes
+            .iter()
+            .flat_map(|l| rhs.boxes.iter().filter_map(|r| l.intersection(r)))
+            .collect();
+        BoxSet { boxes }
+    }
+}
+
+impl<T, const N: usize> SetUnion<Self> for BoxSet<T, N>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn union(&self, rhs: &Self) -> Self {
+        let mut result = self.clone();
+        for b in rhs.boxes.iter() {
+            result.insert(b.clone());
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Overlaps<Hyperrect<T, N>> for BoxSet<T, N>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &Hyperrect<T, N>) -> bool {
+        self.boxes.iter().any(|b| b.overlaps(other))
+    }
+}
+
+impl<T, const N: usize> Includes<PointN<T, N>> for BoxSet<T, N>
+where
+    T: PartialOrd,
+{
+    fn includes(&self, other: &PointN<T, N>) -> bool {
+        self.boxes.iter().any(|b| b.includes(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::includes::Includes;
+    use crate::overlaps::Overlaps;
+    use crate::point_n::PointN;
+    use crate::set::box_set::{BoxSet, Hyperrect};
+    use crate::set::{SetDifference, SetIntersection, SetUnion};
+
+    fn cube(start: i32, end: i32) -> Hyperrect<i32, 3> {
+        Hyperrect::new([start..=end, start..=end, start..=end]).unwrap()
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn hyperrect() {
+        let a = cube(0, 9);
+        let b = cube(5, 14);
+        assert_eq!(a.volume(), 1000);
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&cube(10, 12)));
+        assert!(a.includes(&cube(2, 3)));
+        assert!(!a.includes(&b));
+        assert_eq!(a.intersection(&b), Some(cube(5, 9)));
+        let diff = a.difference(&b);
+        assert!(diff.len() <= 6);
+        assert_eq!(diff.iter().map(|d| d.volume()).sum::<i32>(), 1000 - 125);
+        assert!(Hyperrect::new([0..=1, 3..=2]).is_none());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = BoxSet::from(cube(0, 9));
+        let b = BoxSet::from(cube(5, 14));
+        assert_eq!(a.union(&b).volume(), 1000 + 1000 - 125);
+        assert_eq!(a.intersection(&b).volume(), 125);
+        assert_eq!(a.difference(&b).volume(), 875);
+        assert_eq!(b.difference(&a).volume(), 875);
+        assert!(a.difference(&a).is_empty());
+
+        let union = a.union(&b);
+        for p in [[0, 0, 0], [14, 14, 14], [9, 10, 5]] {
+            let point = PointN::new(p);
+            let expected = cube(0, 9).includes(&point) || cube(5, 14).includes(&point);
+            assert_eq!(union.includes(&point), expected);
+        }
+    }
+
+    #[test]
+    fn four_dimensions() {
+        let all = Hyperrect::new([1u64..=4000, 1..=4000, 1..=4000, 1..=4000]).unwrap();
+        let low_x = Hyperrect::new([1u64..=1350, 1..=4000, 1..=4000, 1..=4000]).unwrap();
+        let set = BoxSet::from(all).difference(&BoxSet::from(low_x));
+        assert_eq!(set.volume(), 2650 * 4000 * 4000 * 4000);
+        let overlapping = BoxSet::new(vec![
+            Hyperrect::new([1u64..=10, 1..=10, 1..=10, 1..=10]).unwrap(),
+            Hyperrect::new([6u64..=15, 6..=15, 6..=15, 6..=15]).unwrap(),
+        ]);
+        assert_eq!(overlapping.volume(), 2 * 10_000 - 625);
+        assert!(overlapping.iter().count() > 1);
+    }
+
+    #[test]
+    fn equality_ignores_how_points_are_split() {
+        let whole = BoxSet::from(Hyperrect::new([0..=9, 0..=9]).unwrap());
+        let halves = BoxSet::new(vec![
+            Hyperrect::new([0..=4, 0..=9]).unwrap(),
+            Hyperrect::new([5..=9, 0..=9]).unwrap(),
+        ]);
+        assert_ne!(whole.iter().count(), halves.iter().count());
+        assert_eq!(whole, halves);
+        assert_eq!(halves, whole);
+        let smaller = BoxSet::from(Hyperrect::new([0..=9, 0..=8]).unwrap());
+        assert_ne!(whole, smaller);
+        assert_ne!(smaller, whole);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::includes::Includes;
+use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
+use std::collections::BTreeSet;
+
+// As with `HashSet`, the inherent methods of the same names return iterators and are called
+// explicitly here.
+
+impl<T> SetDifference<Self> for BTreeSet<T>
+where
+    T: Ord + Clone,
+{
+    fn difference(&self, rhs: &Self) -> Self {
+        BTreeSet::difference(self, rhs).cloned().collect()
+    }
+}
+
+impl<T> SetIntersection<Self> for BTreeSet<T>
+where
+    T: Ord + Clone,
+{
+    fn intersection(&self, rhs: &Self) -> Self {
+        BTreeSet::intersection(self, rhs).cloned().collect()
+    }
+}
+
+impl<T> SetUnion<Self> for BTreeSet<T>
+where
+    T: Ord + Clone,
+{
+    fn union(&self, rhs: &Self) -> Self {
+        BTreeSet::union(self, rhs).cloned().collect()
+    }
+}
+
+impl<T> SetSymmetricDifference<Self> for BTreeSet<T>
+where
+    T: Ord + Clone,
+{
+    fn symmetric_difference(&self, rhs: &Self) -> Self {
+        BTreeSet::symmetric_difference(self, rhs).cloned().collect()
+    }
+}
+
+impl<T> Includes<T> for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn includes(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
+impl<T> Includes<Self> for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn includes(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::includes::Includes;
+    use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
+    use std::collections::BTreeSet;
+
+    /// Written against the traits only, as solver code would be.
+    fn shared_and_unique<S>(a: &S, b: &S) -> (S, S)
+    where
+        S: SetIntersection<S> + SetSymmetricDifference<S>,
+    {
+        (a.intersection(b), a.symmetric_difference(b))
+    }
+
+    #[test]
+    fn set_operations() {
+        let set_a = BTreeSet::from([1, 2, 3, 4]);
+        let set_b = BTreeSet::from([3, 4, 5]);
+        assert_eq!(
+            SetDifference::difference(&set_a, &set_b),
+            BTreeSet::from([1, 2])
+        );
+        assert_eq!(
+            SetUnion::union(&set_a, &set_b),
+            BTreeSet::from([1, 2, 3, 4, 5])
+        );
+        let (shared, unique) = shared_and_unique(&set_a, &set_b);
+        assert_eq!(shared, BTreeSet::from([3, 4]));
+        assert_eq!(unique, BTreeSet::from([1, 2, 5]));
+    }
+
+    #[test]
+    fn includes() {
+        let set = BTreeSet::from([1, 2, 3]);
+        assert!(set.includes(&2));
+        assert!(!set.includes(&4));
+        assert!(set.includes(&BTreeSet::from([1, 3])));
+        assert!(!set.includes(&BTreeSet::from([1, 4])));
+    }
+}
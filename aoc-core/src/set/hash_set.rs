@@ -0,0 +1,108 @@
+use crate::includes::Includes;
+use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+// `HashSet` has inherent methods of the same names that return iterators, so the trait
+// implementations below call those explicitly and collect the results.
+
+impl<T, S> SetDifference<Self> for HashSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    fn difference(&self, rhs: &Self) -> Self {
+        HashSet::difference(self, rhs).cloned().collect()
+    }
+}
+
+impl<T, S> SetIntersection<Self> for HashSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    fn intersection(&self, rhs: &Self) -> Self {
+        HashSet::intersection(self, rhs).cloned().collect()
+    }
+}
+
+impl<T, S> SetUnion<Self> for HashSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    fn union(&self, rhs: &Self) -> Self {
+        HashSet::union(self, rhs).cloned().collect()
+    }
+}
+
+impl<T, S> SetSymmetricDifference<Self> for HashSet<T, S>
+where
+    T: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    fn symmetric_difference(&self, rhs: &Self) -> Self {
+        HashSet::symmetric_difference(self, rhs).cloned().collect()
+    }
+}
+
+impl<T, S> Includes<T> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn includes(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+
+impl<T, S> Includes<Self> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn includes(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::includes::Includes;
+    use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
+    use std::collections::HashSet;
+
+    fn sorted(set: HashSet<u8>) -> Vec<u8> {
+        let mut values: Vec<u8> = set.into_iter().collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn set_operations() {
+        let set_a: HashSet<u8> = HashSet::from([1, 2, 3, 4]);
+        let set_b: HashSet<u8> = HashSet::from([3, 4, 5]);
+        assert_eq!(
+            sorted(SetDifference::difference(&set_a, &set_b)),
+            vec![1, 2]
+        );
+        assert_eq!(
+            sorted(SetIntersection::intersection(&set_a, &set_b)),
+            vec![3, 4]
+        );
+        assert_eq!(sorted(SetUnion::union(&set_a, &set_b)), vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            sorted(SetSymmetricDifference::symmetric_difference(&set_a, &set_b)),
+            vec![1, 2, 5]
+        );
+    }
+
+    #[test]
+    fn includes() {
+        let set: HashSet<u8> = HashSet::from([1, 2, 3]);
+        assert!(set.includes(&2));
+        assert!(!set.includes(&4));
+        assert!(set.includes(&HashSet::from([1, 3])));
+        assert!(!set.includes(&HashSet::from([1, 4])));
+    }
+}
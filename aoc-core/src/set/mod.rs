@@ -1,3 +1,7 @@
+pub mod bit_set;
+pub mod box_set;
+pub mod btree_set;
+pub mod hash_set;
 pub mod range_set;
 
 pub trait SetDifference<T> {
@@ -11,3 +15,7 @@ pub trait SetIntersection<T> {
 pub trait SetUnion<T> {
     fn union(&self, rhs: &T) -> Self;
 }
+
+pub trait SetSymmetricDifference<T> {
+    fn symmetric_difference(&self, rhs: &T) -> Self;
+}
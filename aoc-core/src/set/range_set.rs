@@ -1,43 +1,123 @@
 use crate::includes::Includes;
-use crate::overlaps::Overlaps;
-use crate::set::{SetDifference, SetIntersection, SetUnion};
+use crate::num::{Bounded, Checked, One, Zero};
+use crate::overlaps::{Intersect, Overlaps};
+use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
-use std::ops::Range;
-use std::slice::Iter;
+use std::ops::{Add, Range, RangeInclusive, Sub};
 
+/// A set of values stored as sorted, non-overlapping, non-adjacent ranges.
+///
+/// Ranges are kept with inclusive bounds internally, so a set may extend all the way to `T::MAX`
+/// (e.g. `1..=u8::MAX`), which a half-open `Range` cannot describe.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RangeSet<T> {
-    ranges: Vec<Range<T>>,
+    ranges: Vec<RangeInclusive<T>>,
 }
 
 impl<T> RangeSet<T>
 where
-    T: PartialOrd + Ord + Clone,
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
 {
+    /// Creates a set from half-open ranges. Empty ranges are ignored.
     pub fn new(ranges: Vec<Range<T>>) -> Self {
+        ranges.into_iter().collect()
+    }
+
+    /// Creates a set from inclusive ranges. Empty ranges are ignored.
+    pub fn from_inclusive(ranges: Vec<RangeInclusive<T>>) -> Self {
+        ranges.into_iter().collect()
+    }
+
+    /// Iterates over the ranges in the set as half-open ranges, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the set includes `T::MAX`, as that has no exclusive upper bound. Use
+    /// [RangeSet::iter_inclusive] for sets that may reach it.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_
+    where
+        T: Bounded,
+    {
+        self.ranges.iter().map(|r| {
+            if *r.end() == T::MAX {
+                panic!("Range ending at T::MAX cannot be expressed as a half-open range!");
+            }
+            *r.start()..*r.end() + T::one()
+        })
+    }
+
+    /// Iterates over the ranges in the set as inclusive ranges, in ascending order.
+    pub fn iter_inclusive(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /// Returns the number of values in the set, or `None` if there are more than `T` can count.
+    pub fn len(&self) -> Option<T>
+    where
+        T: Zero<T> + Checked,
+    {
+        self.ranges.iter().try_fold(T::zero(), |acc, r| {
+            let size = r.end().checked_sub(*r.start())?.checked_add(T::one())?;
+            acc.checked_add(size)
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| *r.start())
+    }
+
+    /// Returns the largest value in the set.
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|r| *r.end())
+    }
+
+    /// Returns the smallest range that includes every value in the set.
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        Some(self.min()?..=self.max()?)
+    }
+
+    /// Iterates over every individual value in the set, in ascending order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_
+    where
+        RangeInclusive<T>: Iterator<Item = T>,
+    {
+        self.ranges.iter().flat_map(|r| r.clone())
+    }
+
+    /// Returns every value in `within` that is not in the set.
+    pub fn complement(&self, within: RangeInclusive<T>) -> Self {
+        RangeSet::from(within).difference(self)
+    }
+
+    fn from_unsorted(ranges: Vec<RangeInclusive<T>>) -> Self {
         let mut r = RangeSet { ranges };
+        r.sort();
         r.cleanup();
         r
     }
 
-    pub fn iter(&self) -> Iter<'_, Range<T>> {
-        self.ranges.iter()
-    }
-
+    /// Merges overlapping and adjacent ranges. The ranges must already be sorted by their start.
     fn cleanup(&mut self) {
-        self.sort();
         // if there's nothing to consolidate, return.
         if self.ranges.len() <= 1 {
             return;
         }
 
-        let mut result: Vec<Range<T>> = vec![];
+        let mut result: Vec<RangeInclusive<T>> = Vec::with_capacity(self.ranges.len());
         let mut current = self.ranges.first().unwrap().clone();
         for step in self.ranges.iter().skip(1) {
-            if current.end <= step.start {
+            // `step` only starts past `current` when `start > end`, so `start - 1` can't underflow.
+            if current.end() < step.start() && *current.end() < *step.start() - T::one() {
                 result.push(current);
                 current = step.clone();
             } else {
-                current.end = current.end.max(step.end.clone());
+                current = *current.start()..=*current.end().max(step.end());
             }
         }
         result.push(current);
@@ -45,69 +125,153 @@ where
     }
 
     fn sort(&mut self) {
-        self.ranges.sort_by(|a, b| a.start.cmp(&b.start));
+        self.ranges.sort_by(|a, b| a.start().cmp(b.start()));
+    }
+}
+
+impl<T> FromIterator<Range<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        // a non-empty half-open range always has an inclusive upper bound of `end - 1`
+        let ranges = iter
+            .into_iter()
+            .filter(|r| r.start < r.end)
+            .map(|r| r.start..=r.end - T::one())
+            .collect();
+        RangeSet::from_unsorted(ranges)
+    }
+}
+
+impl<T> FromIterator<RangeInclusive<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let ranges = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        RangeSet::from_unsorted(ranges)
+    }
+}
+
+impl<T> From<Range<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn from(value: Range<T>) -> Self {
+        [value].into_iter().collect()
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn from(value: RangeInclusive<T>) -> Self {
+        [value].into_iter().collect()
     }
 }
 
+/// The set operations below each walk both sets once, in order. As both sides are sorted and
+/// non-overlapping, they run in `O(n + m)` and their output needs no further cleanup.
 impl<T> SetDifference<Self> for RangeSet<T>
 where
-    T: PartialOrd + Ord + Clone + Display,
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
 {
     fn difference(&self, rhs: &Self) -> Self {
-        let result = rhs
-            .ranges
-            .iter()
-            .fold(self.ranges.clone(), |acc, rhs_range| {
-                let mut step_result: Vec<Range<T>> = vec![];
-                for lhs_range in acc {
-                    // if no overlap at all, the entire lhs range remains in the range set
-                    if !lhs_range.overlaps(rhs_range) {
-                        step_result.push(lhs_range);
-                    } else {
-                        // otherwise, keep only the ranges not in rhs
-                        if lhs_range.start < rhs_range.start {
-                            step_result.push(lhs_range.start..rhs_range.start.clone());
-                        }
-                        if lhs_range.end > rhs_range.end {
-                            step_result.push(rhs_range.end.clone()..lhs_range.end);
-                        }
-                    }
+        let mut result = vec![];
+        let mut j = 0usize;
+        for lhs_range in self.ranges.iter() {
+            let mut start = *lhs_range.start();
+            let end = *lhs_range.end();
+            // rhs ranges that end before this one starts can't affect it, or any later one
+            while j < rhs.ranges.len() && *rhs.ranges[j].end() < start {
+                j += 1;
+            }
+            let mut remaining = true;
+            let mut k = j;
+            while remaining && k < rhs.ranges.len() && *rhs.ranges[k].start() <= end {
+                let rhs_range = &rhs.ranges[k];
+                // the bounds being compared strictly means neither step below can overflow.
+                if *rhs_range.start() > start {
+                    result.push(start..=*rhs_range.start() - T::one());
                 }
-                step_result
-            });
+                if *rhs_range.end() >= end {
+                    remaining = false;
+                } else {
+                    start = *rhs_range.end() + T::one();
+                    k += 1;
+                }
+            }
+            if remaining {
+                result.push(start..=end);
+            }
+        }
 
-        RangeSet::new(result)
+        RangeSet { ranges: result }
     }
 }
 
 impl<T> SetIntersection<Self> for RangeSet<T>
 where
-    T: PartialOrd + Ord + Clone,
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
 {
     fn intersection(&self, rhs: &Self) -> Self {
         let mut result = vec![];
-        for lhs_range in self.ranges.iter() {
-            for rhs_range in rhs.ranges.iter() {
-                if lhs_range.start < rhs_range.end && lhs_range.end > rhs_range.start {
-                    let start = lhs_range.start.clone().max(rhs_range.start.clone());
-                    let end = lhs_range.end.clone().min(rhs_range.end.clone());
-                    result.push(start..end);
+        let (mut i, mut j) = (0usize, 0usize);
+        while i < self.ranges.len() && j < rhs.ranges.len() {
+            let lhs_range = &self.ranges[i];
+            let rhs_range = &rhs.ranges[j];
+            let start = *lhs_range.start().max(rhs_range.start());
+            let end = *lhs_range.end().min(rhs_range.end());
+            if start <= end {
+                result.push(start..=end);
+            }
+            // whichever range ends first can't overlap anything further along the other side
+            match lhs_range.end().cmp(rhs_range.end()) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
                 }
             }
         }
 
-        RangeSet::new(result)
+        RangeSet { ranges: result }
     }
 }
 
 impl<T> SetUnion<Self> for RangeSet<T>
 where
-    T: PartialOrd + Ord + Clone,
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
 {
     fn union(&self, rhs: &Self) -> Self {
-        let mut result = self.ranges.clone();
-        result.append(&mut rhs.ranges.clone());
-        RangeSet::new(result)
+        let mut ranges = Vec::with_capacity(self.ranges.len() + rhs.ranges.len());
+        let mut lhs_iter = self.ranges.iter().peekable();
+        let mut rhs_iter = rhs.ranges.iter().peekable();
+        while let (Some(l), Some(r)) = (lhs_iter.peek(), rhs_iter.peek()) {
+            if l.start() <= r.start() {
+                ranges.push(lhs_iter.next().unwrap().clone());
+            } else {
+                ranges.push(rhs_iter.next().unwrap().clone());
+            }
+        }
+        ranges.extend(lhs_iter.cloned());
+        ranges.extend(rhs_iter.cloned());
+
+        let mut result = RangeSet { ranges };
+        result.cleanup();
+        result
+    }
+}
+
+impl<T> SetSymmetricDifference<Self> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn symmetric_difference(&self, rhs: &Self) -> Self {
+        self.difference(rhs).union(&rhs.difference(self))
     }
 }
 
@@ -120,6 +284,82 @@ where
     }
 }
 
+impl<T> Includes<RangeInclusive<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn includes(&self, other: &RangeInclusive<T>) -> bool {
+        RangeSet::from(other.clone()).difference(self).is_empty()
+    }
+}
+
+impl<T> Includes<Range<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn includes(&self, other: &Range<T>) -> bool {
+        RangeSet::from(other.clone()).difference(self).is_empty()
+    }
+}
+
+impl<T> Includes<RangeSet<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn includes(&self, other: &RangeSet<T>) -> bool {
+        other.difference(self).is_empty()
+    }
+}
+
+impl<T> Overlaps<T> for RangeSet<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &T) -> bool {
+        self.includes(other)
+    }
+}
+
+impl<T> Overlaps<RangeInclusive<T>> for RangeSet<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &RangeInclusive<T>) -> bool {
+        self.ranges.iter().any(|r| r.overlaps(other))
+    }
+}
+
+impl<T> Overlaps<Range<T>> for RangeSet<T>
+where
+    T: PartialOrd,
+{
+    fn overlaps(&self, other: &Range<T>) -> bool {
+        self.ranges.iter().any(|r| r.overlaps(other))
+    }
+}
+
+impl<T> Overlaps<RangeSet<T>> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+{
+    fn overlaps(&self, other: &RangeSet<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+}
+
+impl<T, R> Intersect<R> for RangeSet<T>
+where
+    T: Ord + Copy + One<T> + Add<Output = T> + Sub<Output = T>,
+    R: Clone + Into<RangeSet<T>>,
+{
+    type Output = RangeSet<T>;
+
+    fn intersect(&self, other: &R) -> Option<Self::Output> {
+        let result = self.intersection(&other.clone().into());
+        (!result.is_empty()).then_some(result)
+    }
+}
+
 impl<T> Display for RangeSet<T>
 where
     T: Display,
@@ -127,7 +367,7 @@ where
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("RangeSet[")?;
         for (i, r) in self.ranges.iter().enumerate() {
-            f.write_fmt(format_args!("{}..{}", r.start, r.end))?;
+            f.write_fmt(format_args!("{}..={}", r.start(), r.end()))?;
             if i != self.ranges.len() - 1 {
                 f.write_str(", ")?;
             }
@@ -141,8 +381,9 @@ where
 #[cfg(test)]
 mod tests {
     use crate::includes::Includes;
+    use crate::overlaps::{Intersect, Overlaps};
     use crate::set::range_set::RangeSet;
-    use crate::set::{SetDifference, SetIntersection, SetUnion};
+    use crate::set::{SetDifference, SetIntersection, SetSymmetricDifference, SetUnion};
 
     #[test]
     fn intersection() {
@@ -189,4 +430,177 @@ mod tests {
             assert_eq!(set_d.includes(&i), expect);
         }
     }
+
+    #[test]
+    fn from_single_range() {
+        assert_eq!(RangeSet::from(3..7), RangeSet::new(vec![3..7]));
+        assert_eq!(RangeSet::from(3..=6), RangeSet::new(vec![3..7]));
+        assert_eq!(
+            RangeSet::from(3..7).values().collect::<Vec<_>>(),
+            vec![3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn inclusive_ranges() {
+        let set_a = RangeSet::from(1..=4000);
+        let set_b = RangeSet::from(1..4001);
+        assert_eq!(set_a.iter().collect::<Vec<_>>(), vec![1..4001]);
+        assert_eq!(set_b.iter_inclusive().collect::<Vec<_>>(), vec![1..=4000]);
+        assert_eq!(set_a.to_string(), "RangeSet[1..=4000]");
+    }
+
+    #[test]
+    fn inclusive_ranges_at_bounds() {
+        let set_a = RangeSet::from_inclusive(vec![250u8..=u8::MAX, 0..=3]);
+        let set_b = RangeSet::from_inclusive(vec![u8::MAX..=u8::MAX, u8::MIN..=u8::MIN]);
+        let set_c = set_a.difference(&set_b);
+        assert_eq!(
+            set_c.iter_inclusive().collect::<Vec<_>>(),
+            vec![1..=3, 250..=254]
+        );
+        assert_eq!(set_c.iter().collect::<Vec<_>>(), vec![1..4, 250..255]);
+        let set_d = set_a.union(&set_b);
+        assert!(set_d.includes(&u8::MAX));
+        assert_eq!(
+            set_d.iter_inclusive().collect::<Vec<_>>(),
+            vec![0..=3, 250..=255]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn merges_overlapping_ranges() {
+        let set_a = RangeSet::from_inclusive(vec![1..=3, 3..=6, 8..=9]);
+        assert_eq!(
+            set_a.iter_inclusive().collect::<Vec<_>>(),
+            vec![1..=6, 8..=9]
+        );
+        let set_b: RangeSet<u32> = vec![0..0, 5..3, 2..3].into_iter().collect();
+        assert_eq!(set_b.iter().collect::<Vec<_>>(), vec![2..3]);
+    }
+
+    #[test]
+    fn merges_adjacent_ranges() {
+        let set_a = RangeSet::new(vec![2..6, 6..8]);
+        assert_eq!(set_a, RangeSet::from(2..8));
+        assert_eq!(set_a.iter().collect::<Vec<_>>(), vec![2..8]);
+        let set_b = RangeSet::from_inclusive(vec![4u8..=u8::MAX, 0..=3]);
+        assert_eq!(set_b, RangeSet::from(0..=u8::MAX));
+        let set_c = RangeSet::from(1..4).union(&RangeSet::from(4..6));
+        assert_eq!(set_c.iter().collect::<Vec<_>>(), vec![1..6]);
+        assert_eq!(set_c, RangeSet::from(1..6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_panics_at_max() {
+        let set = RangeSet::from(0u8..=u8::MAX);
+        set.iter().for_each(drop);
+    }
+
+    #[test]
+    fn difference_spanning_ranges() {
+        let set_a = RangeSet::new(vec![0..10, 12..20, 25..30]);
+        let set_b = RangeSet::new(vec![2..4, 6..14, 16..17, 19..26, 40..50]);
+        assert_eq!(
+            set_a.difference(&set_b).iter().collect::<Vec<_>>(),
+            vec![0..2, 4..6, 14..16, 17..19, 26..30]
+        );
+        assert_eq!(
+            set_b.difference(&set_a).iter().collect::<Vec<_>>(),
+            vec![10..12, 20..25, 40..50]
+        );
+        assert_eq!(
+            set_a.intersection(&set_b).iter().collect::<Vec<_>>(),
+            vec![2..4, 6..10, 12..14, 16..17, 19..20, 25..26]
+        );
+    }
+
+    #[test]
+    fn measure_and_bounds() {
+        let set = RangeSet::new(vec![12..20, 2..5, 6..9]);
+        assert_eq!(set.len(), Some(14));
+        assert!(!set.is_empty());
+        assert_eq!(set.min(), Some(2));
+        assert_eq!(set.max(), Some(19));
+        assert_eq!(set.span(), Some(2..=19));
+
+        let empty: RangeSet<u32> = RangeSet::new(vec![]);
+        assert_eq!(empty.len(), Some(0));
+        assert!(empty.is_empty());
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.span(), None);
+
+        let wide = RangeSet::from(-100i8..=100);
+        assert_eq!(wide.len(), None);
+        assert_eq!(RangeSet::from(-100i8..=26).len(), Some(i8::MAX));
+        assert_eq!(RangeSet::from(0u8..=u8::MAX).len(), None);
+        let split = RangeSet::from_inclusive(vec![-100i8..=-20, 50..=100]);
+        assert_eq!(split.len(), None);
+        assert_eq!(
+            RangeSet::from_inclusive(vec![-100i8..=-60, 60..=80]).len(),
+            Some(62)
+        );
+    }
+
+    #[test]
+    fn values() {
+        let set = RangeSet::from_inclusive(vec![253u8..=u8::MAX, 1..=2]);
+        assert_eq!(set.values().collect::<Vec<_>>(), vec![1, 2, 253, 254, 255]);
+    }
+
+    #[test]
+    fn complement() {
+        let set = RangeSet::new(vec![2..5, 6..9]);
+        assert_eq!(
+            set.complement(0..=9).iter().collect::<Vec<_>>(),
+            vec![0..2, 5..6, 9..10]
+        );
+        let full = RangeSet::from(0u8..=u8::MAX);
+        assert!(full.complement(0..=u8::MAX).is_empty());
+        let edges = RangeSet::from(1u8..=254);
+        assert_eq!(edges.complement(0..=u8::MAX).values().count(), 2);
+    }
+
+    #[test]
+    fn symmetric_difference() {
+        let set_a = RangeSet::new(vec![2..5, 6..9]);
+        let set_b = RangeSet::from(3..7);
+        let set_c = set_a.symmetric_difference(&set_b);
+        assert_eq!(set_c, set_b.symmetric_difference(&set_a));
+        assert_eq!(set_c.values().collect::<Vec<_>>(), vec![2, 5, 7, 8]);
+    }
+
+    #[test]
+    fn includes_and_overlaps_ranges() {
+        let set = RangeSet::new(vec![2..6, 6..8, 10..12]);
+        assert!(set.includes(&(3..8)));
+        assert!(set.includes(&(3..=7)));
+        assert!(!set.includes(&(3..=8)));
+        assert!(set.includes(&RangeSet::new(vec![2..4, 10..11])));
+        assert!(!set.includes(&RangeSet::new(vec![2..4, 9..11])));
+
+        assert!(set.overlaps(&7));
+        assert!(!set.overlaps(&8));
+        assert!(set.overlaps(&(7..9)));
+        assert!(!set.overlaps(&(8..10)));
+        assert!(set.overlaps(&(8..=10)));
+        assert!(!set.overlaps(&RangeSet::from(8..10)));
+        assert!(set.overlaps(&RangeSet::new(vec![0..1, 11..20])));
+    }
+
+    #[test]
+    fn intersect() {
+        let set = RangeSet::new(vec![2..5, 6..9]);
+        assert_eq!(
+            set.intersect(&(4..7)),
+            Some(RangeSet::new(vec![4..5, 6..7]))
+        );
+        assert_eq!(set.intersect(&(5..=5)), None);
+        assert_eq!(
+            set.intersect(&RangeSet::from(0..3)),
+            Some(RangeSet::from(2..3))
+        );
+    }
 }
@@ -0,0 +1,141 @@
+use crate::answers::Answers;
+use crate::bench::Timings;
+use crate::fetch::find_session;
+use crate::puzzle_input::PuzzleInput;
+use crate::scanner::InputError;
+use crate::submit::{SubmissionLog, Submitter, Verdict};
+use crate::workspace;
+use std::env;
+use std::fmt::Display;
+use std::process::exit;
+
+/// A day's puzzle, split into parsing and the two parts so that each can be run (and timed) on
+/// its own.
+pub trait Solution {
+    /// The puzzle input, parsed into whatever both parts work on.
+    type Input;
+
+    /// Describes the answer to part one when it is printed.
+    const PART_ONE: &'static str = "Part one";
+    /// Describes the answer to part two when it is printed.
+    const PART_TWO: &'static str = "Part two";
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, InputError>;
+
+    fn part_one(input: &Self::Input) -> impl Display;
+
+    fn part_two(input: &Self::Input) -> impl Display;
+
+    /// Prints anything else worth knowing about the input, after both answers. This is skipped
+    /// when benchmarking.
+    fn report(_input: &Self::Input) {}
+}
+
+/// Parses `input` and returns the answers to both parts, as they would be printed.
+pub fn answers<S: Solution>(input: &PuzzleInput) -> Result<Answers, InputError> {
+    let parsed = S::parse(input)?;
+    let part_one = S::part_one(&parsed).to_string();
+    let part_two = S::part_two(&parsed).to_string();
+    Ok(Answers::new(part_one, part_two))
+}
+
+/// Panics unless both parts still give the answers recorded in the day's `answers.txt`. Each day
+/// calls this from a test, so that changes to shared code can't quietly break old answers.
+pub fn check_answers<S: Solution>() {
+    let path = Answers::default_path();
+    let recorded = Answers::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "Couldn't read {}: {} (record it with --record-answers)",
+            path.display(),
+            e
+        )
+    });
+    let actual = answers::<S>(&PuzzleInput::default()).unwrap_or_else(|e| panic!("{}", e));
+    let mismatches = recorded.mismatches(&actual);
+    if !mismatches.is_empty() {
+        let lines: Vec<String> = mismatches.iter().map(|m| format!("\n  {}", m)).collect();
+        panic!("Answers differ from {}:{}", path.display(), lines.concat());
+    }
+}
+
+/// The entry point for a day's binary. Prints both answers for the day's `input.txt`.
+///
+/// With `--bench`, times parsing and each part instead. `--bench --record` prints the timings as
+/// a single line for the `bench` binary to collect. `--record-answers` saves both answers to the
+/// day's `answers.txt`, for [check_answers]. `--submit 1` or `--submit 2` sends that part's
+/// answer to the site.
+pub fn run<S: Solution>() {
+    let input = PuzzleInput::default();
+    let args: Vec<String> = env::args().collect();
+    match args.iter().skip(1).map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => {
+            let parsed = S::parse(&input).unwrap_or_else(|e| e.exit());
+            println!("{}: {}", S::PART_ONE, S::part_one(&parsed));
+            println!("{}: {}", S::PART_TWO, S::part_two(&parsed));
+            S::report(&parsed);
+        }
+        ["--bench"] => {
+            let timings = Timings::measure::<S>(&input).unwrap_or_else(|e| e.exit());
+            println!("{}", timings);
+        }
+        ["--bench", "--record"] => {
+            let timings = Timings::measure::<S>(&input).unwrap_or_else(|e| e.exit());
+            println!("{}", timings.to_record());
+        }
+        ["--record-answers"] => {
+            let answers = answers::<S>(&input).unwrap_or_else(|e| e.exit());
+            let path = Answers::default_path();
+            answers.write(&path).unwrap();
+            print!("Recorded in {}:\n{}", path.display(), answers);
+        }
+        ["--submit", part @ ("1" | "2")] => submit::<S>(&input, part.parse().unwrap()),
+        _ => {
+            eprintln!(
+                "Usage: {} [--bench [--record] | --record-answers | --submit <1|2>]",
+                args[0]
+            );
+            exit(2);
+        }
+    }
+}
+
+/// Submits one part's answer, and records it as known-good if it was right.
+fn submit<S: Solution>(input: &PuzzleInput, part: u8) {
+    let (Some(year), Some(day)) = (input.year(), input.day()) else {
+        eprintln!("Can't tell which year and day this is");
+        exit(1);
+    };
+    let Some(session) = find_session(workspace::root()) else {
+        eprintln!("Set AOC_SESSION or write the session cookie to .session");
+        exit(1);
+    };
+
+    let parsed = S::parse(input).unwrap_or_else(|e| e.exit());
+    let answer = match part {
+        1 => S::part_one(&parsed).to_string(),
+        _ => S::part_two(&parsed).to_string(),
+    };
+    println!(
+        "Submitting {} for {} day {} part {}",
+        answer, year, day, part
+    );
+    let submitter = Submitter::new(year, &session, SubmissionLog::default_path());
+    match submitter.submit(day, part, &answer) {
+        Ok(verdict) => {
+            println!("Verdict: {}", verdict);
+            if verdict == Verdict::Correct {
+                let path = Answers::default_path();
+                let mut answers = Answers::read(&path).unwrap_or_default();
+                match part {
+                    1 => answers.part_one = Some(answer),
+                    _ => answers.part_two = Some(answer),
+                }
+                answers.write(&path).unwrap();
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,524 @@
+use crate::fetch::http::{Curl, HttpClient, Response};
+use crate::fetch::BASE_URL;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, OpenOptions};
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    /// Wrong, with no hint as to which way.
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous attempt, and this much longer has to pass first.
+    Wait(Duration),
+    /// The part was already solved, or the part before it wasn't.
+    WrongLevel,
+    /// Anything the response couldn't be matched against.
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site answers a submission with.
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if body.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if body.contains("You gave an answer too recently") {
+            let left = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(left, _)| parse_duration(left));
+            Verdict::Wait(left.unwrap_or(Duration::from_secs(60)))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::Wrong => f.write_str("wrong"),
+            Verdict::TooHigh => f.write_str("too-high"),
+            Verdict::TooLow => f.write_str("too-low"),
+            Verdict::Wait(left) => f.write_fmt(format_args!("wait-{}s", left.as_secs())),
+            Verdict::WrongLevel => f.write_str("wrong-level"),
+            Verdict::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Verdict::Correct,
+            "wrong" => Verdict::Wrong,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong-level" => Verdict::WrongLevel,
+            "unknown" => Verdict::Unknown,
+            _ => {
+                let secs = s
+                    .strip_prefix("wait-")
+                    .and_then(|s| s.strip_suffix('s'))
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| s.to_owned())?;
+                Verdict::Wait(Duration::from_secs(secs))
+            }
+        })
+    }
+}
+
+/// Parses durations the way the site writes them, e.g. `5m 12s` or `40s`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for part in s.split_whitespace() {
+        let unit = match part.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        secs += part[..part.len() - 1].parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// One answer that was sent to the site, and what came back.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{}\t{}\t{}\t{}",
+            self.time, self.part, self.answer, self.verdict
+        ))
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [time, part, answer, verdict] = fields[..] else {
+            return Err(s.to_owned());
+        };
+        Ok(Attempt {
+            time: time.parse().map_err(|_| s.to_owned())?,
+            part: part.parse().map_err(|_| s.to_owned())?,
+            answer: answer.to_owned(),
+            verdict: verdict.parse()?,
+        })
+    }
+}
+
+/// Every attempt made for a day, kept as one tab-separated line per attempt in a
+/// `submissions.txt` next to its `input.txt`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SubmissionLog {
+    pub attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// The `submissions.txt` in the directory of the currently executing crate.
+    pub fn default_path() -> PathBuf {
+        let base_path = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        [&base_path, "submissions.txt"].iter().collect()
+    }
+
+    /// Reads the log at `path`, which is empty if nothing has been submitted yet. Lines that
+    /// can't be read are skipped.
+    pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match read_to_string(path) {
+            Ok(s) => Ok(SubmissionLog {
+                attempts: s.lines().filter_map(|line| line.parse().ok()).collect(),
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn append<P: AsRef<Path>>(&mut self, path: P, attempt: Attempt) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", attempt)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Checks whether `answer` is worth sending for `part` at `now`, going by earlier attempts.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let attempts: Vec<&Attempt> = self.attempts.iter().filter(|a| a.part == part).collect();
+        if let Some(correct) = attempts.iter().find(|a| a.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadySolved(correct.answer.clone()));
+        }
+        if let Some(wrong) = attempts
+            .iter()
+            .find(|a| a.answer == answer && a.verdict.is_wrong())
+        {
+            return Err(Refusal::KnownWrong(wrong.verdict));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |verdict| {
+                attempts
+                    .iter()
+                    .filter(move |a| a.verdict == verdict)
+                    .filter_map(|a| a.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| value >= high) {
+                return Err(Refusal::TooHigh(high));
+            }
+            if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| value <= low) {
+                return Err(Refusal::TooLow(low));
+            }
+        }
+        // The site's cooldowns apply to the whole day, not just one part
+        let waiting = self.attempts.iter().rev().find_map(|a| match a.verdict {
+            Verdict::Wait(left) => Some(a.time + left.as_secs()),
+            _ => None,
+        });
+        if let Some(until) = waiting.filter(|&until| until > now) {
+            return Err(Refusal::Wait(Duration::from_secs(until - now)));
+        }
+        Ok(())
+    }
+}
+
+/// Why an answer wasn't sent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Refusal {
+    /// The part was already solved, with this answer.
+    AlreadySolved(String),
+    /// The same answer was already sent and judged wrong.
+    KnownWrong(Verdict),
+    /// The answer is at least as high as one that was too high.
+    TooHigh(i128),
+    /// The answer is at most as low as one that was too low.
+    TooLow(i128),
+    /// The site asked to wait, and this much of the wait is left.
+    Wait(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                f.write_fmt(format_args!("already solved with {}", answer))
+            }
+            Refusal::KnownWrong(verdict) => {
+                f.write_fmt(format_args!("already submitted and was {}", verdict))
+            }
+            Refusal::TooHigh(high) => f.write_fmt(format_args!("{} was already too high", high)),
+            Refusal::TooLow(low) => f.write_fmt(format_args!("{} was already too low", low)),
+            Refusal::Wait(left) => f.write_fmt(format_args!("{}s left to wait", left.as_secs())),
+        }
+    }
+}
+
+/// Posts answers to the site, logging every attempt and refusing ones the log shows are pointless.
+pub struct Submitter<C: HttpClient = Curl> {
+    client: C,
+    base_url: String,
+    year: u16,
+    session: String,
+    log_path: PathBuf,
+}
+
+impl Submitter {
+    /// Submits to the real site with the given session cookie.
+    pub fn new<P: AsRef<Path>>(year: u16, session: &str, log_path: P) -> Self {
+        Submitter::with_client(Curl, BASE_URL, year, session, log_path)
+    }
+}
+
+impl<C> Submitter<C>
+where
+    C: HttpClient,
+{
+    pub fn with_client<P: AsRef<Path>>(
+        client: C,
+        base_url: &str,
+        year: u16,
+        session: &str,
+        log_path: P,
+    ) -> Self {
+        Submitter {
+            client,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            year,
+            session: session.trim().to_owned(),
+            log_path: log_path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, SubmitError> {
+        if !(1..=25).contains(&day) || !(1..=2).contains(&part) {
+            return Err(SubmitError::InvalidPuzzle(day, part));
+        }
+        let log_error = |e| SubmitError::Log(self.log_path.clone(), e);
+        let mut log = SubmissionLog::read(&self.log_path).map_err(log_error)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        log.check(part, answer, now).map_err(SubmitError::Refused)?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, self.year, day);
+        let cookie = format!("session={}", self.session);
+        let level = part.to_string();
+        let response = self
+            .client
+            .post(
+                &url,
+                &[("Cookie", &cookie)],
+                &[("level", &level), ("answer", answer)],
+            )
+            .map_err(SubmitError::Http)?;
+        if response.status != 200 {
+            return Err(SubmitError::Status(response));
+        }
+
+        let verdict = Verdict::from_response(&response.body);
+        let attempt = Attempt {
+            time: now,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+        };
+        log.append(&self.log_path, attempt).map_err(log_error)?;
+        Ok(verdict)
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// Only days 1 to 25 and parts 1 and 2 exist.
+    InvalidPuzzle(u8, u8),
+    /// The answer wasn't sent, going by earlier attempts.
+    Refused(Refusal),
+    /// The request couldn't be sent, or the response couldn't be read.
+    Http(io::Error),
+    /// The site answered with something other than `200 OK`.
+    Status(Response),
+    /// The log of attempts couldn't be read or written.
+    Log(PathBuf, io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::InvalidPuzzle(day, part) => {
+                f.write_fmt(format_args!("There is no day {} part {}", day, part))
+            }
+            SubmitError::Refused(refusal) => f.write_fmt(format_args!("Not sent: {}", refusal)),
+            SubmitError::Http(e) => f.write_fmt(format_args!("Request failed: {}", e)),
+            SubmitError::Status(response) => f.write_fmt(format_args!(
+                "Site answered {}: {}",
+                response.status,
+                response.body.trim()
+            )),
+            SubmitError::Log(path, e) => {
+                f.write_fmt(format_args!("Couldn't use {}: {}", path.display(), e))
+            }
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::http::PlainHttp;
+    use crate::submit::{Attempt, Refusal, SubmissionLog, SubmitError, Submitter, Verdict};
+    use std::fs::{read_to_string, remove_file};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    fn attempt(time: u64, part: u8, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            time,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response(&page("That's not the right answer. If you're stuck...")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait."
+            )),
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::from_response("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn log_round_trip() {
+        for verdict in [
+            Verdict::Correct,
+            Verdict::TooLow,
+            Verdict::Wait(Duration::from_secs(40)),
+            Verdict::WrongLevel,
+        ] {
+            let attempt = attempt(1701388800, 2, "abc 12", verdict);
+            assert_eq!(attempt.to_string().parse(), Ok(attempt));
+        }
+        assert!("1701388800\t2\tabc".parse::<Attempt>().is_err());
+    }
+
+    #[test]
+    fn refuses_pointless_attempts() {
+        let log = SubmissionLog {
+            attempts: vec![
+                attempt(100, 1, "50", Verdict::TooHigh),
+                attempt(200, 1, "10", Verdict::TooLow),
+                attempt(300, 1, "abc", Verdict::Wrong),
+                attempt(400, 2, "7", Verdict::Correct),
+            ],
+        };
+        assert_eq!(log.check(1, "30", 1000), Ok(()));
+        assert_eq!(
+            log.check(1, "50", 1000),
+            Err(Refusal::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(log.check(1, "60", 1000), Err(Refusal::TooHigh(50)));
+        assert_eq!(log.check(1, "-3", 1000), Err(Refusal::TooLow(10)));
+        assert_eq!(
+            log.check(1, "abc", 1000),
+            Err(Refusal::KnownWrong(Verdict::Wrong))
+        );
+        assert_eq!(
+            log.check(2, "8", 1000),
+            Err(Refusal::AlreadySolved(String::from("7")))
+        );
+
+        let waiting = SubmissionLog {
+            attempts: vec![attempt(100, 1, "1", Verdict::Wait(Duration::from_secs(60)))],
+        };
+        assert_eq!(
+            waiting.check(2, "2", 130),
+            Err(Refusal::Wait(Duration::from_secs(30)))
+        );
+        assert_eq!(waiting.check(2, "2", 160), Ok(()));
+    }
+
+    #[test]
+    fn posts_answers_and_logs_them() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request = Vec::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some(value) = line.strip_prefix("Content-Length: ") {
+                    length = value.trim().parse().unwrap();
+                }
+                request.push(line.trim_end().to_owned());
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push(String::from_utf8(body).unwrap());
+            (&stream)
+                .write_all(b"HTTP/1.0 200 OK\r\n\r\n<article>That's the right answer!</article>")
+                .unwrap();
+            request
+        });
+
+        let log_path =
+            std::env::temp_dir().join(format!("aoc-submissions-{}.txt", std::process::id()));
+        let _ = remove_file(&log_path);
+        let submitter = Submitter::with_client(PlainHttp, &base_url, 2023, "abc", &log_path);
+
+        assert_eq!(submitter.submit(3, 1, "4361").unwrap(), Verdict::Correct);
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "POST /2023/day/3/answer HTTP/1.0");
+        assert!(request.contains(&String::from("Cookie: session=abc")));
+        assert_eq!(request.last().unwrap(), "level=1&answer=4361");
+        assert!(read_to_string(&log_path)
+            .unwrap()
+            .ends_with("\t1\t4361\tcorrect\n"));
+
+        // Nothing is listening any more, so this only passes if it is refused before sending
+        assert!(matches!(
+            submitter.submit(3, 1, "4361"),
+            Err(SubmitError::Refused(Refusal::AlreadySolved(_)))
+        ));
+        assert!(matches!(
+            submitter.submit(3, 3, "1"),
+            Err(SubmitError::InvalidPuzzle(3, 3))
+        ));
+        remove_file(&log_path).unwrap();
+    }
+}
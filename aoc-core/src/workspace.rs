@@ -0,0 +1,89 @@
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+
+/// The root of the workspace, which holds `aoc-core` and a directory per year, each with an
+/// `aoc-NN` crate per day.
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// The directory of a day's crate under `root`, e.g. `2023/aoc-01`.
+pub fn day_dir<P: AsRef<Path>>(root: P, year: u16, day: u8) -> PathBuf {
+    root.as_ref()
+        .join(year.to_string())
+        .join(format!("aoc-{:02}", day))
+}
+
+/// The name of a day's package and binary, which has to be unique across years.
+pub fn package_name(year: u16, day: u8) -> String {
+    format!("aoc-{}-{:02}", year, day)
+}
+
+/// Every year with a directory under `root`, in order.
+pub fn years<P: AsRef<Path>>(root: P) -> Vec<u16> {
+    let mut years: Vec<u16> = read_dir(root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| parse_year(entry.file_name().to_str()?))
+        .collect();
+    years.sort();
+    years
+}
+
+/// Reads a year from a directory name, which must be four digits.
+pub fn parse_year(name: &str) -> Option<u16> {
+    (name.len() == 4 && name.chars().all(|c| c.is_ascii_digit()))
+        .then(|| name.parse().ok())
+        .flatten()
+}
+
+/// Takes a `--year <year>` pair out of `args`, falling back to the latest year under `root`.
+/// Returns `None` if the year after `--year` isn't one, or there's no year to fall back to.
+pub fn take_year<P: AsRef<Path>>(args: &mut Vec<String>, root: P) -> Option<u16> {
+    match args.iter().position(|arg| arg == "--year") {
+        Some(i) if i + 1 < args.len() => {
+            let year = parse_year(&args[i + 1]);
+            args.drain(i..=i + 1);
+            year
+        }
+        Some(_) => None,
+        None => years(root).last().copied(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::workspace::{day_dir, package_name, parse_year, root, take_year, years};
+    use std::path::Path;
+
+    #[test]
+    fn lays_out_days_by_year() {
+        assert_eq!(day_dir("/aoc", 2023, 5), Path::new("/aoc/2023/aoc-05"));
+        assert_eq!(package_name(2015, 25), "aoc-2015-25");
+        assert_eq!(parse_year("2023"), Some(2023));
+        assert_eq!(parse_year("aoc-core"), None);
+        assert_eq!(parse_year("202"), None);
+    }
+
+    #[test]
+    fn finds_years() {
+        assert!(years(root()).contains(&2023));
+        assert!(root().join("aoc-core").is_dir());
+    }
+
+    #[test]
+    fn takes_year_argument() {
+        let mut args: Vec<String> = ["3", "--year", "2015", "x"].map(String::from).to_vec();
+        assert_eq!(take_year(&mut args, root()), Some(2015));
+        assert_eq!(args, vec!["3", "x"]);
+
+        let latest = *years(root()).last().unwrap();
+        assert_eq!(take_year(&mut args, root()), Some(latest));
+        assert_eq!(args, vec!["3", "x"]);
+
+        let mut args: Vec<String> = vec![String::from("--year")];
+        assert_eq!(take_year(&mut args, root()), None);
+    }
+}